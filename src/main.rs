@@ -1,11 +1,16 @@
 use x509_lint::x509_parser::prelude::CertificateRevocationList;
-use x509_lint::{crl_rfc_lints, x509_parser, CRLLintRegistry, LintDefinition, LintResult};
+use x509_lint::{
+    cert_crl_rfc_lints, crl_rfc_lints, ocsp_rfc_lints, x509_parser, CRLLintRegistry,
+    CertificateCRLLintRegistry, LintConfig, LintDefinition, LintResult, OcspLintRegistry,
+    OcspResponse,
+};
 
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use colored::Colorize;
 use std::error::Error;
 use std::io;
 use x509_lint::{rfc_lints, CertificateLintRegistry};
+use x509_parser::asn1_rs::Oid;
 use x509_parser::pem::*;
 use x509_parser::prelude::{FromDer, X509Certificate};
 
@@ -26,21 +31,71 @@ pub struct Args {
     print_lints: bool,
 
     /// Force interpreting file as certificate (default: auto-detect)
-    #[clap(long = "cert")]
-    force_cert: bool,
+    ///
+    /// When used with `--crl FILE`, also check that each certificate is consistent with the CRL
+    #[clap(long = "cert", value_name = "FILE", num_args = 0..=1)]
+    cert: Option<Option<String>>,
 
     /// Force interpreting file as CRL (default: auto-detect)
-    #[clap(long = "crl")]
-    force_crl: bool,
+    #[clap(long = "crl", value_name = "FILE", num_args = 0..=1)]
+    crl: Option<Option<String>>,
+
+    /// Private extension allowed to be marked critical (can be repeated)
+    #[clap(long = "allow-critical", value_name = "OID", value_parser = parse_oid)]
+    allowed_critical_extensions: Vec<Oid<'static>>,
+
+    /// Minimum size of RSA moduli, in bits
+    #[clap(long = "rsa-min-bits", value_name = "BITS", default_value_t = 2048)]
+    rsa_min_modulus_bits: usize,
+
+    /// Weak algorithm which should not be reported (can be repeated)
+    #[clap(long = "allow-weak-algorithm", value_name = "OID", value_parser = parse_oid)]
+    allowed_weak_algorithms: Vec<Oid<'static>>,
+
+    /// Blocklist of Debian weak keys, in `openssl-blacklist` format (can be repeated)
+    #[clap(long = "debian-blocklist", value_name = "FILE")]
+    debian_blocklists: Vec<String>,
+
+    /// Maximum interval between thisUpdate and nextUpdate of CRLs, in days
+    #[clap(long = "crl-max-days", value_name = "DAYS", default_value_t = 10)]
+    crl_max_validity_days: u32,
+
+    /// Maximum interval between thisUpdate and nextUpdate of CRLs for CA certificates, in days
+    #[clap(long = "ca-crl-max-days", value_name = "DAYS", default_value_t = 365)]
+    ca_crl_max_validity_days: u32,
+
+    /// Maximum interval between thisUpdate and nextUpdate of OCSP responses, in days
+    #[clap(long = "ocsp-max-days", value_name = "DAYS", default_value_t = 10)]
+    ocsp_max_validity_days: u32,
 
     /// Input file, or standard input if none was provided
     #[clap(group = "input")]
     input_file: Option<String>,
 }
 
+impl Args {
+    fn force_cert(&self) -> bool {
+        self.cert.is_some()
+    }
+
+    fn force_crl(&self) -> bool {
+        self.crl.is_some()
+    }
+
+    /// Input file: positional argument, or the value of `--cert` or `--crl`
+    fn input_file(&self) -> Option<&str> {
+        self.input_file
+            .as_deref()
+            .or(self.cert.as_ref().and_then(|f| f.as_deref()))
+            .or(self.crl.as_ref().and_then(|f| f.as_deref()))
+    }
+}
+
 struct Registries<'a> {
     cert: CertificateLintRegistry<'a>,
     crl: CRLLintRegistry<'a>,
+    cert_crl: CertificateCRLLintRegistry<'a>,
+    ocsp: OcspLintRegistry<'a>,
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -60,7 +115,29 @@ fn main() -> Result<(), Box<dyn Error>> {
         std::process::exit(0);
     }
 
-    match process_certs(&args) {
+    let mut config = LintConfig {
+        allowed_critical_extensions: args.allowed_critical_extensions.clone(),
+        rsa_min_modulus_bits: args.rsa_min_modulus_bits,
+        allowed_weak_algorithms: args.allowed_weak_algorithms.clone(),
+        crl_max_validity_days: args.crl_max_validity_days,
+        ca_crl_max_validity_days: args.ca_crl_max_validity_days,
+        ocsp_max_validity_days: args.ocsp_max_validity_days,
+        ..Default::default()
+    };
+    for path in &args.debian_blocklists {
+        config
+            .load_debian_weak_keys(path)
+            .map_err(|e| format!("{path}: {e}"))?;
+    }
+    let reg = registries(config);
+
+    let result = match (&args.cert, &args.crl) {
+        (Some(Some(cert_file)), Some(Some(crl_file))) => {
+            process_cert_crl(cert_file, crl_file, &reg)
+        }
+        _ => process_certs(&args, &reg),
+    };
+    match result {
         Ok(_) => Ok(()),
         Err(e) => {
             println!("{e}");
@@ -73,14 +150,52 @@ fn print_lints() {
     println!("Certificate Lints:");
     let cert_registry = rfc_lints();
 
-    for (lint_definition, _) in cert_registry.lints() {
+    let definitions = cert_registry
+        .lints()
+        .map(|(definition, _)| definition)
+        .chain(
+            cert_registry
+                .configurable_lints()
+                .map(|(definition, _)| definition),
+        );
+    for lint_definition in definitions {
         print_lint(lint_definition);
     }
 
     println!("CRL Lints:");
     let crl_registry = crl_rfc_lints();
 
-    for (lint_definition, _) in crl_registry.lints() {
+    let definitions = crl_registry
+        .lints()
+        .map(|(definition, _)| definition)
+        .chain(
+            crl_registry
+                .configurable_lints()
+                .map(|(definition, _)| definition),
+        );
+    for lint_definition in definitions {
+        print_lint(lint_definition);
+    }
+
+    println!("Certificate/CRL Lints:");
+    let cert_crl_registry = cert_crl_rfc_lints();
+
+    for (lint_definition, _) in cert_crl_registry.lints() {
+        print_lint(lint_definition);
+    }
+
+    println!("OCSP Response Lints:");
+    let ocsp_registry = ocsp_rfc_lints();
+
+    let definitions = ocsp_registry
+        .lints()
+        .map(|(definition, _)| definition)
+        .chain(
+            ocsp_registry
+                .configurable_lints()
+                .map(|(definition, _)| definition),
+        );
+    for lint_definition in definitions {
         print_lint(lint_definition);
     }
 }
@@ -99,17 +214,65 @@ fn print_lint(lint_definition: &LintDefinition<'_>) {
     println!("{s}");
 }
 
-fn process_certs(args: &Args) -> Result<(), Box<dyn Error>> {
-    let cert_registry = rfc_lints();
-    let crl_registry = crl_rfc_lints();
+fn process_certs(args: &Args, reg: &Registries) -> Result<(), Box<dyn Error>> {
+    for der in read_input(args.input_file())? {
+        x509_lint(&der, args, reg)?;
+    }
+
+    Ok(())
+}
 
-    let reg = Registries {
-        cert: cert_registry,
-        crl: crl_registry,
+/// Lint the certificates and the CRL, and check that each certificate is consistent with the CRL
+fn process_cert_crl(
+    cert_file: &str,
+    crl_file: &str,
+    reg: &Registries,
+) -> Result<(), Box<dyn Error>> {
+    let crl_der = match read_input(Some(crl_file))?.as_slice() {
+        [crl_der] => crl_der.clone(),
+        [] => return Err(format!("{crl_file}: no CRL found").into()),
+        objects => {
+            let msg = format!(
+                "{crl_file}: expected a single CRL, found {} objects",
+                objects.len()
+            );
+            return Err(msg.into());
+        }
     };
+    let (_rem, crl) = CertificateRevocationList::from_der(&crl_der)?;
+
+    println!("CRL Issuer: {}", crl.issuer());
+    print_results(&reg.crl.run_lints(&crl));
+
+    let certs_der = read_input(Some(cert_file))?;
+    if certs_der.is_empty() {
+        return Err(format!("{cert_file}: no certificate found").into());
+    }
+    for cert_der in &certs_der {
+        let (_rem, x509) = X509Certificate::from_der(cert_der)?;
+
+        println!("Subject: {}", x509.subject());
+        print_results(&reg.cert.run_lints(&x509));
+        println!("Certificate/CRL consistency:");
+        print_results(&reg.cert_crl.run_lints(&x509, &crl));
+    }
+
+    Ok(())
+}
+
+fn registries<'a>(config: LintConfig) -> Registries<'a> {
+    Registries {
+        cert: rfc_lints().with_config(config.clone()),
+        crl: crl_rfc_lints().with_config(config.clone()),
+        cert_crl: cert_crl_rfc_lints(),
+        ocsp: ocsp_rfc_lints().with_config(config),
+    }
+}
 
+/// Read file (or stdin if `None`), and return the DER objects it contains
+fn read_input(input_file: Option<&str>) -> Result<Vec<Vec<u8>>, Box<dyn Error>> {
     // read file or stdin
-    let mut input: Box<dyn std::io::Read + 'static> = if let Some(input_file) = &args.input_file {
+    let mut input: Box<dyn std::io::Read + 'static> = if let Some(input_file) = input_file {
         let f = std::fs::File::open(input_file)?;
         Box::new(f)
     } else {
@@ -122,6 +285,7 @@ fn process_certs(args: &Args) -> Result<(), Box<dyn Error>> {
     input.read_to_end(&mut buffer_vec)?;
 
     let data = &buffer_vec;
+    let mut objects = Vec::new();
 
     // try to guess if PEM, base64 or DER
     if data.starts_with(b"Certificate:\n") {
@@ -137,8 +301,7 @@ fn process_certs(args: &Args) -> Result<(), Box<dyn Error>> {
                     if pem.label.as_str() != "CERTIFICATE" {
                         eprintln!("Warning: PEM is not a certificate?!");
                     }
-                    let der = &pem.contents;
-                    x509_lint(der, args, &reg)?;
+                    objects.push(pem.contents);
                 }
             }
         }
@@ -146,40 +309,56 @@ fn process_certs(args: &Args) -> Result<(), Box<dyn Error>> {
         // probably PEM
         for pem in Pem::iter_from_buffer(data) {
             let pem = pem?;
-            if pem.label.as_str() != "CERTIFICATE" {
-                eprintln!("Warning: PEM is not a certificate?!");
+            if !matches!(pem.label.as_str(), "CERTIFICATE" | "X509 CRL") {
+                eprintln!("Warning: PEM is not a certificate or a CRL?!");
             }
-            let der = &pem.contents;
-            x509_lint(der, args, &reg)?;
+            objects.push(pem.contents);
         }
     } else if test_base64(data) {
         // base64
-        let der = STANDARD.decode(data)?;
-        x509_lint(&der, args, &reg)?;
+        objects.push(STANDARD.decode(data)?);
     } else if data.starts_with(&[0x30]) {
         // DER
-        x509_lint(data, args, &reg)?;
+        objects.push(buffer_vec);
     } else {
         eprintln!("Could not determine input format");
         std::process::exit(2);
     }
 
-    Ok(())
+    Ok(objects)
 }
 
 fn x509_lint(der: &[u8], args: &Args, reg: &Registries) -> Result<(), Box<dyn Error>> {
-    let lint_results = if args.force_cert {
+    let lint_results = if args.force_cert() {
         x509_cert_lint(der, args, &reg.cert)?
-    } else if args.force_crl {
+    } else if args.force_crl() {
         x509_crl_lint(der, args, &reg.crl)?
     } else {
-        // auto-detect: try as certificate, if not as CRL
+        // auto-detect: try as certificate, if not as CRL, and then as OCSP response
         match x509_cert_lint(der, args, &reg.cert) {
             Ok(t) => t,
-            Err(_) => x509_crl_lint(der, args, &reg.crl)?,
+            Err(cert_err) => match x509_crl_lint(der, args, &reg.crl) {
+                Ok(t) => t,
+                Err(crl_err) => match x509_ocsp_lint(der, args, &reg.ocsp) {
+                    Ok(t) => t,
+                    Err(ocsp_err) => {
+                        let msg = format!(
+                            "Could not parse input as certificate ({cert_err}), \
+                             CRL ({crl_err}) or OCSP response ({ocsp_err})"
+                        );
+                        return Err(msg.into());
+                    }
+                },
+            },
         }
     };
 
+    print_results(&lint_results);
+
+    Ok(())
+}
+
+fn print_results(lint_results: &[(&LintDefinition, LintResult)]) {
     if lint_results.is_empty() {
         println!("  No warnings/errors");
     }
@@ -200,8 +379,6 @@ fn x509_lint(der: &[u8], args: &Args, reg: &Registries) -> Result<(), Box<dyn Er
 
         println!("{s}");
     }
-
-    Ok(())
 }
 
 fn x509_cert_lint<'a>(
@@ -230,6 +407,26 @@ fn x509_crl_lint<'a>(
     Ok(lint_results)
 }
 
+fn x509_ocsp_lint<'a>(
+    der: &'a [u8],
+    _args: &'a Args,
+    registry: &'a OcspLintRegistry<'a>,
+) -> Result<Vec<(&'a LintDefinition<'a>, LintResult)>, Box<dyn Error>> {
+    let (_rem, response) = OcspResponse::from_der(der)?;
+
+    match &response.basic_response {
+        Some(basic) => println!("OCSP Responder: {}", basic.tbs_response_data.responder_id),
+        None => println!("OCSP Response: {}", response.response_status),
+    }
+
+    let lint_results = registry.run_lints(&response);
+    Ok(lint_results)
+}
+
+fn parse_oid(s: &str) -> Result<Oid<'static>, String> {
+    s.parse().map_err(|_| format!("invalid OID '{s}'"))
+}
+
 // attempt to guess if data is base64-encoded
 fn test_base64(data: &[u8]) -> bool {
     if data.len() % 4 == 0 && data.last() == Some(&b'=') {
@@ -0,0 +1,77 @@
+use x509_parser::certificate::X509Certificate;
+use x509_parser::prelude::CertificateRevocationList;
+
+use super::*;
+
+/// Prototype for lint function checking the consistency of a X.509 certificate and a CRL
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+/// use x509_lint::x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+///
+/// fn test_same_issuer(x509: &X509Certificate<'_>, crl: &CertificateRevocationList<'_>) -> LintResult {
+///     if x509.issuer() != crl.issuer() {
+///         LintResult::new(LintStatus::Error)
+///     } else {
+///         LintResult::pass()
+///     }
+/// }
+/// ```
+pub type CertificateCRLLint =
+    fn(_x509: &X509Certificate, _crl: &CertificateRevocationList) -> LintResult;
+
+/// Helper macro to implement a new [`CertificateCRLLint`]
+///
+/// If `$link_fn` returns true, emit lint warning
+///
+/// _Note_: This macro is mostly useful if the test is small and simple. In other
+/// cases, it is best to implement a function with [`CertificateCRLLint`] signature.
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+/// use x509_lint::x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+///
+/// cert_crl_lint!(
+///     test_same_issuer,
+///     LintStatus::Error,
+///     |x509: &X509Certificate<'_>, crl: &CertificateRevocationList<'_>| x509.issuer() != crl.issuer()
+/// );
+/// ```
+#[macro_export]
+macro_rules! cert_crl_lint {
+    ($vis:vis $lint_fn:ident, $lvl:expr, $lint:expr) => {
+        #[allow(unused_qualifications)]
+        $vis fn $lint_fn(
+            x509: &x509_parser::certificate::X509Certificate,
+            crl: &x509_parser::revocation_list::CertificateRevocationList,
+        ) -> LintResult {
+            let f = $lint;
+            if f(x509, crl) {
+                $crate::LintResult::new($lvl)
+            } else {
+                $crate::LintResult::pass()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // test macro for certificate/CRL lint definition
+    cert_crl_lint!(
+        test_lint1,
+        LintStatus::Error,
+        |x509: &X509Certificate, crl: &CertificateRevocationList| x509.issuer() != crl.issuer()
+    );
+
+    #[test]
+    fn test_cert_crl_macro() {
+        let _ = test_lint1;
+    }
+}
@@ -20,6 +20,28 @@ use super::*;
 /// ```
 pub type CertificateLint = fn(_x509: &X509Certificate) -> LintResult;
 
+/// Prototype for X.509 certificate lint function, receiving the lint configuration
+///
+/// The configuration is the one of the registry running the lint (see
+/// [`CertificateLintRegistry::with_config`]).
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+/// use x509_lint::x509_parser::prelude::X509Certificate;
+///
+/// fn test_rsa_key_size(x509: &X509Certificate<'_>, config: &LintConfig) -> LintResult {
+///     if x509.public_key().parsed().map_or(0, |key| key.key_size()) < config.rsa_min_modulus_bits {
+///         LintResult::new(LintStatus::Error)
+///     } else {
+///         LintResult::pass()
+///     }
+/// }
+/// ```
+pub type ConfigurableCertificateLint =
+    fn(_x509: &X509Certificate, _config: &LintConfig) -> LintResult;
+
 /// Helper macro to implement a new [`CertificateLint`]
 ///
 /// If `$link_fn` returns true, emit lint warning
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+use std::path::Path;
+use std::{fs, io};
+
+use x509_parser::asn1_rs::Oid;
+use x509_parser::oid_registry::{OID_EC_P256, OID_NIST_EC_P384, OID_NIST_EC_P521};
+
+/// Configuration parameters for lints
+///
+/// The configuration is stored in a lint registry (see for ex.
+/// [`CertificateLintRegistry::with_config`](crate::CertificateLintRegistry::with_config)), and
+/// passed to the configurable lints when running them.
+///
+/// ```rust
+/// use x509_lint::{rfc_lints, LintConfig};
+///
+/// let mut config = LintConfig::default();
+/// config.allowed_critical_extensions.push("1.3.6.1.4.1.311.21.10".parse().unwrap());
+/// let registry = rfc_lints().with_config(config);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    /// Private extensions known by relying parties, which are allowed to be marked critical
+    pub allowed_critical_extensions: Vec<Oid<'static>>,
+    /// Minimum size of RSA moduli, in bits (default: 2048)
+    pub rsa_min_modulus_bits: usize,
+    /// Allowed named curves for EC public keys (default: P-256, P-384 and P-521)
+    pub allowed_ec_curves: Vec<Oid<'static>>,
+    /// Weak algorithms (signature algorithms, hash algorithms or key types) which are
+    /// not reported by the weak algorithm lints
+    pub allowed_weak_algorithms: Vec<Oid<'static>>,
+    /// Fingerprints of Debian weak keys (see [`LintConfig::load_debian_weak_keys`])
+    pub debian_weak_keys: HashSet<String>,
+    /// Maximum interval between thisUpdate and nextUpdate of CRLs, in days (default: 10)
+    pub crl_max_validity_days: u32,
+    /// Maximum interval between thisUpdate and nextUpdate of CRLs only containing CA
+    /// certificates, or without IssuingDistributionPoint extension, in days (default: 365)
+    pub ca_crl_max_validity_days: u32,
+    /// Maximum interval between thisUpdate and nextUpdate of OCSP responses, in days
+    /// (default: 10)
+    pub ocsp_max_validity_days: u32,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        LintConfig {
+            allowed_critical_extensions: Vec::new(),
+            rsa_min_modulus_bits: 2048,
+            allowed_ec_curves: vec![OID_EC_P256, OID_NIST_EC_P384, OID_NIST_EC_P521],
+            allowed_weak_algorithms: Vec::new(),
+            debian_weak_keys: HashSet::new(),
+            crl_max_validity_days: 10,
+            ca_crl_max_validity_days: 365,
+            ocsp_max_validity_days: 10,
+        }
+    }
+}
+
+impl LintConfig {
+    /// Load a blocklist of Debian weak keys, in the format of the `openssl-blacklist` package
+    ///
+    /// Each line contains the last 20 hexadecimal characters of the SHA-1 of the string
+    /// `Modulus=<HEX>\n`. Empty lines and comments (starting with `#`) are ignored.
+    pub fn load_debian_weak_keys(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        let content = fs::read_to_string(path)?;
+        let fingerprints = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(str::to_ascii_lowercase);
+        self.debian_weak_keys.extend(fingerprints);
+        Ok(())
+    }
+}
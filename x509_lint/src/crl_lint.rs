@@ -20,6 +20,30 @@ use super::*;
 /// ```
 pub type CRLLint = fn(_crl: &CertificateRevocationList) -> LintResult;
 
+/// Prototype for Certificate Revocation List lint function, receiving the lint configuration
+///
+/// The configuration is the one of the registry running the lint (see
+/// [`CRLLintRegistry::with_config`]).
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+/// use x509_lint::x509_parser::prelude::CertificateRevocationList;
+///
+/// fn test_crl_validity(crl: &CertificateRevocationList<'_>, config: &LintConfig) -> LintResult {
+///     let max_seconds = i64::from(config.crl_max_validity_days) * 86400;
+///     match crl.next_update() {
+///         Some(next) if next.timestamp() - crl.last_update().timestamp() > max_seconds => {
+///             LintResult::new(LintStatus::Error)
+///         }
+///         _ => LintResult::pass(),
+///     }
+/// }
+/// ```
+pub type ConfigurableCRLLint =
+    fn(_crl: &CertificateRevocationList, _config: &LintConfig) -> LintResult;
+
 /// Helper macro to implement a new Certificate Revocation List lint
 ///
 /// If `$link_fn` returns true, emit lint warning
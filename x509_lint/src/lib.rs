@@ -1,7 +1,11 @@
 //! X.509 Certificate and CRL linter
 //!
-//! This crate provides two kinds of lints: [`CertificateLint`] for X.509 Certificates,
-//! and [`CRLLint`] for X.509 Certificate Revocation Lists.
+//! This crate provides four kinds of lints: [`CertificateLint`] for X.509 Certificates,
+//! [`CRLLint`] for X.509 Certificate Revocation Lists, [`CertificateCRLLint`] to check
+//! that a certificate and a CRL are consistent, and [`OcspLint`] for OCSP responses.
+//!
+//! OCSP responses are not supported by `x509_parser`: this crate includes a parser for them
+//! (see [`OcspResponse`]).
 //!
 //! Lints are simple functions receiving a parsed object, and returning a [`LintResult`].
 //!
@@ -29,12 +33,28 @@
 //! `run_lints` returns a list of [`LintDefinition`] and [`LintResult`].
 //! The lifetime of definitions is the lifetime of the registry containing them.
 //!
-//! Similarly, [`crl_rfc_lints`] returns a registry for CRL lints.
+//! Similarly, [`crl_rfc_lints`] returns a registry for CRL lints, [`cert_crl_rfc_lints`]
+//! a registry for certificate/CRL consistency lints, and [`ocsp_rfc_lints`] a registry for
+//! OCSP response lints.
+//!
+//! Some lints depend on parameters (for ex. the minimum RSA key size): they receive the
+//! [`LintConfig`] stored in the registry, which can be changed using `with_config`:
+//!
+//! ```rust
+//! use x509_lint::*;
+//!
+//! let config = LintConfig {
+//!     rsa_min_modulus_bits: 3072,
+//!     ..Default::default()
+//! };
+//! let registry = rfc_lints().with_config(config);
+//! ```
 //!
 //! # Adding lints
 //!
 //! To add a new lint to a registry, a [`LintDefinition`] and a function are required.
-//! Helpers macros [`certificate_lint`] and [`crl_lint`] are provided to simplify declaration.
+//! Helpers macros [`certificate_lint`], [`crl_lint`], [`cert_crl_lint`] and [`ocsp_lint`] are
+//! provided to simplify declaration.
 //!
 //! Example:
 //!
@@ -92,17 +112,25 @@ unreachable_pub
 ))]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod cert_crl_lint;
 mod certificate_lint;
+mod config;
 mod crl_lint;
 mod definition;
+mod ocsp;
+mod ocsp_lint;
 mod registry;
 mod result;
 mod rfc;
 mod status;
 
+pub use cert_crl_lint::*;
 pub use certificate_lint::*;
+pub use config::*;
 pub use crl_lint::*;
 pub use definition::*;
+pub use ocsp::*;
+pub use ocsp_lint::*;
 pub use registry::*;
 pub use result::*;
 pub use rfc::*;
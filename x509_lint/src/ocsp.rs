@@ -0,0 +1,475 @@
+//! Minimal parser for OCSP responses (RFC6960)
+//!
+//! `x509_parser` does not provide OCSP support. This module only parses responses (not
+//! requests), and does not verify signatures.
+
+use std::fmt;
+
+use x509_parser::asn1_rs::{oid, Any, BitString, Class, FromDer, Oid, Tag};
+use x509_parser::error::{X509Error, X509Result};
+use x509_parser::extensions::X509Extension;
+use x509_parser::nom;
+use x509_parser::prelude::{X509Certificate, X509Name};
+use x509_parser::time::ASN1Time;
+use x509_parser::x509::{AlgorithmIdentifier, ReasonCode};
+
+/// Response type `id-pkix-ocsp-basic`
+#[rustfmt::skip]
+pub const OID_PKIX_OCSP_BASIC: Oid<'static> = oid!(1.3.6.1.5.5.7.48.1.1);
+
+/// Status of an OCSP response (`OCSPResponseStatus`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OcspResponseStatus(pub u32);
+
+impl OcspResponseStatus {
+    /// Response has valid confirmations
+    pub const SUCCESSFUL: Self = Self(0);
+    /// Illegal confirmation request
+    pub const MALFORMED_REQUEST: Self = Self(1);
+    /// Internal error in issuer
+    pub const INTERNAL_ERROR: Self = Self(2);
+    /// Try again later
+    pub const TRY_LATER: Self = Self(3);
+    /// Must sign the request
+    pub const SIG_REQUIRED: Self = Self(5);
+    /// Request unauthorized
+    pub const UNAUTHORIZED: Self = Self(6);
+
+    /// Return the name of the status, or `None` if the value is not defined (`4` is not used)
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self.0 {
+            0 => "successful",
+            1 => "malformedRequest",
+            2 => "internalError",
+            3 => "tryLater",
+            5 => "sigRequired",
+            6 => "unauthorized",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+impl fmt::Display for OcspResponseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// OCSP response
+///
+/// <pre>
+/// OCSPResponse ::= SEQUENCE {
+///    responseStatus         OCSPResponseStatus,
+///    responseBytes          [0] EXPLICIT ResponseBytes OPTIONAL }
+///
+/// ResponseBytes ::=       SEQUENCE {
+///    responseType   OBJECT IDENTIFIER,
+///    response       OCTET STRING }
+/// </pre>
+///
+/// If the response type is `id-pkix-ocsp-basic`, the response is parsed into `basic_response`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcspResponse<'a> {
+    /// Status of the response
+    pub response_status: OcspResponseStatus,
+    /// Type of the response, if `responseBytes` is present
+    pub response_type: Option<Oid<'a>>,
+    /// Parsed basic response
+    pub basic_response: Option<BasicOcspResponse<'a>>,
+}
+
+/// Basic OCSP response
+///
+/// <pre>
+/// BasicOCSPResponse       ::= SEQUENCE {
+///    tbsResponseData      ResponseData,
+///    signatureAlgorithm   AlgorithmIdentifier,
+///    signature            BIT STRING,
+///    certs            [0] EXPLICIT SEQUENCE OF Certificate OPTIONAL }
+/// </pre>
+#[derive(Debug, Clone, PartialEq)]
+pub struct BasicOcspResponse<'a> {
+    /// Signed data of the response
+    pub tbs_response_data: ResponseData<'a>,
+    /// Signature algorithm
+    pub signature_algorithm: AlgorithmIdentifier<'a>,
+    /// Signature value
+    pub signature_value: BitString<'a>,
+    /// Certificates included to help verifying the signature
+    pub certs: Vec<X509Certificate<'a>>,
+}
+
+/// Signed data of a basic OCSP response
+///
+/// <pre>
+/// ResponseData ::= SEQUENCE {
+///    version              [0] EXPLICIT Version DEFAULT v1,
+///    responderID              ResponderID,
+///    producedAt               GeneralizedTime,
+///    responses                SEQUENCE OF SingleResponse,
+///    responseExtensions   [1] EXPLICIT Extensions OPTIONAL }
+/// </pre>
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseData<'a> {
+    /// Version (`0` for v1)
+    pub version: u32,
+    /// Identifier of the responder
+    pub responder_id: ResponderId<'a>,
+    /// Time at which the response was signed
+    pub produced_at: ASN1Time,
+    /// Status of the requested certificates
+    pub responses: Vec<SingleResponse<'a>>,
+    /// Response extensions
+    pub extensions: Vec<X509Extension<'a>>,
+}
+
+/// Identifier of the OCSP responder
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponderId<'a> {
+    /// Name of the responder (`byName`)
+    ByName(X509Name<'a>),
+    /// SHA-1 hash of the responder's public key (`byKey`)
+    ByKey(&'a [u8]),
+}
+
+impl fmt::Display for ResponderId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResponderId::ByName(name) => write!(f, "{name}"),
+            ResponderId::ByKey(hash) => hash.iter().try_for_each(|b| write!(f, "{b:02X}")),
+        }
+    }
+}
+
+/// Status of a single certificate
+///
+/// <pre>
+/// SingleResponse ::= SEQUENCE {
+///    certID                       CertID,
+///    certStatus                   CertStatus,
+///    thisUpdate                   GeneralizedTime,
+///    nextUpdate         [0]       EXPLICIT GeneralizedTime OPTIONAL,
+///    singleExtensions   [1]       EXPLICIT Extensions OPTIONAL }
+/// </pre>
+#[derive(Debug, Clone, PartialEq)]
+pub struct SingleResponse<'a> {
+    /// Identifier of the certificate
+    pub cert_id: CertId<'a>,
+    /// Revocation status of the certificate
+    pub cert_status: CertStatus,
+    /// Time at which the status is known to be correct
+    pub this_update: ASN1Time,
+    /// Time at or before which newer information will be available
+    pub next_update: Option<ASN1Time>,
+    /// Single response extensions
+    pub extensions: Vec<X509Extension<'a>>,
+}
+
+/// Identifier of a certificate
+///
+/// <pre>
+/// CertID ::= SEQUENCE {
+///    hashAlgorithm           AlgorithmIdentifier,
+///    issuerNameHash          OCTET STRING,
+///    issuerKeyHash           OCTET STRING,
+///    serialNumber            CertificateSerialNumber }
+/// </pre>
+#[derive(Debug, Clone, PartialEq)]
+pub struct CertId<'a> {
+    /// Hash algorithm used for `issuer_name_hash` and `issuer_key_hash`
+    pub hash_algorithm: AlgorithmIdentifier<'a>,
+    /// Hash of the issuer's distinguished name
+    pub issuer_name_hash: &'a [u8],
+    /// Hash of the issuer's public key
+    pub issuer_key_hash: &'a [u8],
+    /// Raw serial number of the certificate
+    pub serial_number: &'a [u8],
+}
+
+/// Revocation status of a certificate
+#[derive(Debug, Clone, PartialEq)]
+pub enum CertStatus {
+    /// Certificate is not revoked
+    Good,
+    /// Certificate is revoked
+    Revoked {
+        /// Time of revocation
+        revocation_time: ASN1Time,
+        /// Reason of revocation, if present
+        revocation_reason: Option<ReasonCode>,
+    },
+    /// Responder does not know about the certificate
+    Unknown,
+}
+
+impl<'a> FromDer<'a, X509Error> for OcspResponse<'a> {
+    /// Parse a DER-encoded OCSP response
+    ///
+    /// The returned object uses zero-copy, and so has the same lifetime as the input.
+    fn from_der(i: &'a [u8]) -> X509Result<'a, Self> {
+        parse_ocsp_response(i).map_err(nom::Err::Error)
+    }
+}
+
+/// Parse an object with the expected tag (universal class), and return its content
+fn expect(i: &[u8], tag: Tag) -> Result<(&[u8], &[u8]), X509Error> {
+    let (rem, any) = Any::from_der(i)?;
+    if any.class() != Class::Universal || any.tag() != tag {
+        return Err(X509Error::Generic);
+    }
+    Ok((rem, any.data))
+}
+
+/// Parse an optional object with the expected context-specific tag, and return its content
+fn opt_tagged(i: &[u8], tag: u32) -> Result<(&[u8], Option<&[u8]>), X509Error> {
+    match Any::from_der(i) {
+        Ok((rem, any)) if any.class() == Class::ContextSpecific && any.tag().0 == tag => {
+            Ok((rem, Some(any.data)))
+        }
+        _ => Ok((i, None)),
+    }
+}
+
+/// Parse a SEQUENCE OF objects from the content of a SEQUENCE
+fn sequence_of<'a, T>(
+    mut i: &'a [u8],
+    parse: impl Fn(&'a [u8]) -> Result<(&'a [u8], T), X509Error>,
+) -> Result<Vec<T>, X509Error> {
+    let mut items = Vec::new();
+    while !i.is_empty() {
+        let (rem, item) = parse(i)?;
+        items.push(item);
+        i = rem;
+    }
+    Ok(items)
+}
+
+fn parse_extensions(i: &[u8]) -> Result<Vec<X509Extension<'_>>, X509Error> {
+    let (_, content) = expect(i, Tag::Sequence)?;
+    sequence_of(content, |i| Ok(X509Extension::from_der(i)?))
+}
+
+fn parse_ocsp_response(i: &[u8]) -> Result<(&[u8], OcspResponse<'_>), X509Error> {
+    let (rem, content) = expect(i, Tag::Sequence)?;
+    let (content, status) = expect(content, Tag::Enumerated)?;
+    let response_status = OcspResponseStatus(parse_u32(status)?);
+    let (_, response_bytes) = opt_tagged(content, 0)?;
+    let (response_type, basic_response) = match response_bytes {
+        Some(response_bytes) => {
+            let (_, content) = expect(response_bytes, Tag::Sequence)?;
+            let (content, oid) = Oid::from_der(content)?;
+            let (_, response) = expect(content, Tag::OctetString)?;
+            let basic = if oid == OID_PKIX_OCSP_BASIC {
+                Some(parse_basic_response(response)?.1)
+            } else {
+                None
+            };
+            (Some(oid), basic)
+        }
+        None => (None, None),
+    };
+    let response = OcspResponse {
+        response_status,
+        response_type,
+        basic_response,
+    };
+    Ok((rem, response))
+}
+
+fn parse_u32(data: &[u8]) -> Result<u32, X509Error> {
+    if data.is_empty() || data.len() > 4 || data[0] & 0x80 != 0 {
+        return Err(X509Error::InvalidNumber);
+    }
+    Ok(data.iter().fold(0, |acc, &b| (acc << 8) | u32::from(b)))
+}
+
+fn parse_basic_response(i: &[u8]) -> Result<(&[u8], BasicOcspResponse<'_>), X509Error> {
+    let (rem, content) = expect(i, Tag::Sequence)?;
+    let (content, tbs_response_data) = parse_response_data(content)?;
+    let (content, signature_algorithm) = AlgorithmIdentifier::from_der(content)?;
+    let (content, signature_value) = BitString::from_der(content)?;
+    let (_, certs) = opt_tagged(content, 0)?;
+    let certs = match certs {
+        Some(certs) => {
+            let (_, certs) = expect(certs, Tag::Sequence)?;
+            sequence_of(certs, |i| Ok(X509Certificate::from_der(i)?))?
+        }
+        None => Vec::new(),
+    };
+    let response = BasicOcspResponse {
+        tbs_response_data,
+        signature_algorithm,
+        signature_value,
+        certs,
+    };
+    Ok((rem, response))
+}
+
+fn parse_response_data(i: &[u8]) -> Result<(&[u8], ResponseData<'_>), X509Error> {
+    let (rem, content) = expect(i, Tag::Sequence)?;
+    let (content, version) = opt_tagged(content, 0)?;
+    let version = match version {
+        Some(version) => parse_u32(expect(version, Tag::Integer)?.1)?,
+        None => 0,
+    };
+    let (content, responder) = Any::from_der(content)?;
+    let responder_id = match (responder.class(), responder.tag().0) {
+        (Class::ContextSpecific, 1) => ResponderId::ByName(X509Name::from_der(responder.data)?.1),
+        (Class::ContextSpecific, 2) => {
+            ResponderId::ByKey(expect(responder.data, Tag::OctetString)?.1)
+        }
+        _ => return Err(X509Error::Generic),
+    };
+    let (content, produced_at) = ASN1Time::from_der(content)?;
+    let (content, responses) = expect(content, Tag::Sequence)?;
+    let responses = sequence_of(responses, parse_single_response)?;
+    let (_, extensions) = opt_tagged(content, 1)?;
+    let extensions = match extensions {
+        Some(extensions) => parse_extensions(extensions)?,
+        None => Vec::new(),
+    };
+    let data = ResponseData {
+        version,
+        responder_id,
+        produced_at,
+        responses,
+        extensions,
+    };
+    Ok((rem, data))
+}
+
+fn parse_single_response(i: &[u8]) -> Result<(&[u8], SingleResponse<'_>), X509Error> {
+    let (rem, content) = expect(i, Tag::Sequence)?;
+    let (content, cert_id) = parse_cert_id(content)?;
+    let (content, status) = Any::from_der(content)?;
+    let cert_status = match (status.class(), status.tag().0) {
+        (Class::ContextSpecific, 0) => CertStatus::Good,
+        (Class::ContextSpecific, 1) => {
+            let (info, revocation_time) = ASN1Time::from_der(status.data)?;
+            let revocation_reason = match opt_tagged(info, 0)? {
+                (_, Some(reason)) => {
+                    let (_, reason) = expect(reason, Tag::Enumerated)?;
+                    let reason =
+                        u8::try_from(parse_u32(reason)?).map_err(|_| X509Error::InvalidNumber)?;
+                    Some(ReasonCode(reason))
+                }
+                _ => None,
+            };
+            CertStatus::Revoked {
+                revocation_time,
+                revocation_reason,
+            }
+        }
+        (Class::ContextSpecific, 2) => CertStatus::Unknown,
+        _ => return Err(X509Error::Generic),
+    };
+    let (content, this_update) = ASN1Time::from_der(content)?;
+    let (content, next_update) = opt_tagged(content, 0)?;
+    let next_update = match next_update {
+        Some(next_update) => Some(ASN1Time::from_der(next_update)?.1),
+        None => None,
+    };
+    let (_, extensions) = opt_tagged(content, 1)?;
+    let extensions = match extensions {
+        Some(extensions) => parse_extensions(extensions)?,
+        None => Vec::new(),
+    };
+    let response = SingleResponse {
+        cert_id,
+        cert_status,
+        this_update,
+        next_update,
+        extensions,
+    };
+    Ok((rem, response))
+}
+
+fn parse_cert_id(i: &[u8]) -> Result<(&[u8], CertId<'_>), X509Error> {
+    let (rem, content) = expect(i, Tag::Sequence)?;
+    let (content, hash_algorithm) = AlgorithmIdentifier::from_der(content)?;
+    let (content, issuer_name_hash) = expect(content, Tag::OctetString)?;
+    let (content, issuer_key_hash) = expect(content, Tag::OctetString)?;
+    let (_, serial_number) = expect(content, Tag::Integer)?;
+    let cert_id = CertId {
+        hash_algorithm,
+        issuer_name_hash,
+        issuer_key_hash,
+        serial_number,
+    };
+    Ok((rem, cert_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rfc::testing::der;
+
+    #[test]
+    fn parse_ocsp() {
+        let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("assets/ocsp_response.der");
+        let data = std::fs::read(path).expect("DER file not found");
+        let (rem, response) = OcspResponse::from_der(&data).expect("Could not parse response");
+        assert!(rem.is_empty());
+        assert_eq!(response.response_status, OcspResponseStatus::SUCCESSFUL);
+        let basic = response.basic_response.expect("no basic response");
+        let data = &basic.tbs_response_data;
+        assert!(matches!(data.responder_id, ResponderId::ByKey(hash) if hash.len() == 20));
+        assert_eq!(data.responses.len(), 1);
+        assert_eq!(data.responses[0].cert_id.serial_number, &[1]);
+        assert!(matches!(
+            data.responses[0].cert_status,
+            CertStatus::Revoked { .. }
+        ));
+        assert!(data.responses[0].next_update.is_some());
+        assert_eq!(data.extensions.len(), 1);
+        assert_eq!(basic.certs.len(), 1);
+    }
+
+    /// SingleResponse for a revoked certificate, with the content of the reason code
+    fn revoked_single_response(reason: &[u8]) -> Vec<u8> {
+        let hash_algorithm = der(
+            0x30,
+            &[der(0x06, &[0x2b, 0x0e, 0x03, 0x02, 0x1a]), der(0x05, &[])].concat(),
+        );
+        let cert_id = der(
+            0x30,
+            &[
+                hash_algorithm,
+                der(0x04, &[0; 20]),
+                der(0x04, &[0; 20]),
+                der(0x02, &[1]),
+            ]
+            .concat(),
+        );
+        let time = der(0x18, b"20250101000000Z");
+        let revoked_info = der(
+            0xa1,
+            &[time.clone(), der(0xa0, &der(0x0a, reason))].concat(),
+        );
+        der(0x30, &[cert_id, revoked_info, time].concat())
+    }
+
+    #[test]
+    fn parse_revocation_reason() {
+        let data = revoked_single_response(&[1]);
+        let (_, single) = parse_single_response(&data).expect("Could not parse response");
+        assert!(matches!(
+            single.cert_status,
+            CertStatus::Revoked {
+                revocation_reason: Some(ReasonCode(1)),
+                ..
+            }
+        ));
+        // reason codes do not fit in an octet
+        let data = revoked_single_response(&[0x01, 0x01]);
+        assert_eq!(
+            parse_single_response(&data).unwrap_err(),
+            X509Error::InvalidNumber
+        );
+    }
+}
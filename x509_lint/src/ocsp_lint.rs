@@ -0,0 +1,95 @@
+use super::*;
+
+/// Prototype for OCSP response lint function
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+///
+/// fn test_response_status(response: &OcspResponse<'_>) -> LintResult {
+///     if response.response_status != OcspResponseStatus::SUCCESSFUL {
+///         LintResult::new(LintStatus::Warn)
+///     } else {
+///         LintResult::pass()
+///     }
+/// }
+/// ```
+pub type OcspLint = fn(_response: &OcspResponse) -> LintResult;
+
+/// Prototype for OCSP response lint function, receiving the lint configuration
+///
+/// The configuration is the one of the registry running the lint (see
+/// [`OcspLintRegistry::with_config`]).
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+///
+/// fn test_single_responses(response: &OcspResponse<'_>, config: &LintConfig) -> LintResult {
+///     let Some(basic) = &response.basic_response else {
+///         return LintResult::pass();
+///     };
+///     let max_seconds = i64::from(config.ocsp_max_validity_days) * 86400;
+///     let too_long = basic.tbs_response_data.responses.iter().any(|single| {
+///         single
+///             .next_update
+///             .is_some_and(|next| next.timestamp() - single.this_update.timestamp() > max_seconds)
+///     });
+///     if too_long {
+///         LintResult::new(LintStatus::Error)
+///     } else {
+///         LintResult::pass()
+///     }
+/// }
+/// ```
+pub type ConfigurableOcspLint = fn(_response: &OcspResponse, _config: &LintConfig) -> LintResult;
+
+/// Helper macro to implement a new [`OcspLint`]
+///
+/// If `$link_fn` returns true, emit lint warning
+///
+/// _Note_: This macro is mostly useful if the test is small and simple. In other
+/// cases, it is best to implement a function with [`OcspLint`] signature.
+///
+/// # Example
+///
+/// ```rust
+/// use x509_lint::*;
+///
+/// ocsp_lint!(
+///     test_response_status,
+///     LintStatus::Warn,
+///     |response: &OcspResponse<'_>| response.response_status != OcspResponseStatus::SUCCESSFUL
+/// );
+/// ```
+#[macro_export]
+macro_rules! ocsp_lint {
+    ($vis:vis $lint_fn:ident, $lvl:expr, $lint:expr) => {
+        #[allow(unused_qualifications)]
+        $vis fn $lint_fn(response: &$crate::OcspResponse) -> LintResult {
+            let f = $lint;
+            if f(response) {
+                $crate::LintResult::new($lvl)
+            } else {
+                $crate::LintResult::pass()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // test macro for OCSP lint definition
+    ocsp_lint!(test_lint1, LintStatus::Warn, |response: &OcspResponse| {
+        response.response_status != OcspResponseStatus::SUCCESSFUL
+    });
+
+    #[test]
+    fn test_ocsp_macro() {
+        let _ = test_lint1;
+    }
+}
@@ -7,12 +7,28 @@ use super::*;
 #[derive(Default)]
 pub struct CertificateLintRegistry<'a> {
     lints: Vec<(LintDefinition<'a>, CertificateLint)>,
+    configurable_lints: Vec<(LintDefinition<'a>, ConfigurableCertificateLint)>,
+    config: LintConfig,
 }
 
 impl<'a> CertificateLintRegistry<'a> {
     /// Build a new registry from provided lint definitions and functions
     pub fn new(lints: Vec<(LintDefinition<'a>, CertificateLint)>) -> Self {
-        Self { lints }
+        Self {
+            lints,
+            ..Default::default()
+        }
+    }
+
+    /// Set the configuration passed to configurable lints (default: [`LintConfig::default`])
+    pub fn with_config(mut self, config: LintConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Return the configuration passed to configurable lints
+    pub fn config(&self) -> &LintConfig {
+        &self.config
     }
 
     /// Return a iterator on the registered lint definitions and functions
@@ -20,14 +36,34 @@ impl<'a> CertificateLintRegistry<'a> {
         self.lints.iter()
     }
 
+    /// Return a iterator on the registered configurable lint definitions and functions
+    pub fn configurable_lints(
+        &self,
+    ) -> impl Iterator<Item = &(LintDefinition<'a>, ConfigurableCertificateLint)> {
+        self.configurable_lints.iter()
+    }
+
     /// Register a new lint definition and function
     pub fn insert(&mut self, lint_definition: LintDefinition<'a>, lint: CertificateLint) {
         self.lints.push((lint_definition, lint));
     }
 
+    /// Register a new lint definition and configurable function
+    pub fn insert_configurable(
+        &mut self,
+        lint_definition: LintDefinition<'a>,
+        lint: ConfigurableCertificateLint,
+    ) {
+        self.configurable_lints.push((lint_definition, lint));
+    }
+
     /// Merge lints from registry `other` in the current registry
+    ///
+    /// The configuration of `other` is ignored.
     pub fn merge(&mut self, mut other: CertificateLintRegistry<'a>) {
         self.lints.append(&mut other.lints);
+        self.configurable_lints
+            .append(&mut other.configurable_lints);
     }
 
     /// Filter lints, keeping only lints with name matching the provided filter
@@ -35,6 +71,8 @@ impl<'a> CertificateLintRegistry<'a> {
     /// Currently, the matching function is `starts_with`, and only one filter can be provided
     pub fn filter(&mut self, filter: &str) {
         self.lints.retain(|(def, _)| def.name.starts_with(filter));
+        self.configurable_lints
+            .retain(|(def, _)| def.name.starts_with(filter));
     }
 
     /// Run lint functions on the certificate, returning only the results of lints not returning `Pass`
@@ -42,16 +80,24 @@ impl<'a> CertificateLintRegistry<'a> {
         &'a self,
         x509: &X509Certificate,
     ) -> Vec<(&'a LintDefinition<'a>, LintResult)> {
-        self.lints
-            .iter()
-            .filter_map(|(lint_definition, lint)| {
-                let r = (*lint)(x509);
-                match r.status {
-                    LintStatus::Pass => None,
-                    _ => Some((lint_definition, r)),
-                }
-            })
-            .collect()
+        let results = self.lints.iter().filter_map(|(lint_definition, lint)| {
+            let r = (*lint)(x509);
+            match r.status {
+                LintStatus::Pass => None,
+                _ => Some((lint_definition, r)),
+            }
+        });
+        let configurable_results =
+            self.configurable_lints
+                .iter()
+                .filter_map(|(lint_definition, lint)| {
+                    let r = (*lint)(x509, &self.config);
+                    match r.status {
+                        LintStatus::Pass => None,
+                        _ => Some((lint_definition, r)),
+                    }
+                });
+        results.chain(configurable_results).collect()
     }
 }
 
@@ -67,12 +113,28 @@ impl<'a> FromIterator<(LintDefinition<'a>, CertificateLint)> for CertificateLint
 #[derive(Default)]
 pub struct CRLLintRegistry<'a> {
     lints: Vec<(LintDefinition<'a>, CRLLint)>,
+    configurable_lints: Vec<(LintDefinition<'a>, ConfigurableCRLLint)>,
+    config: LintConfig,
 }
 
 impl<'a> CRLLintRegistry<'a> {
     /// Build a new registry from provided lint definitions and functions
     pub fn new(lints: Vec<(LintDefinition<'a>, CRLLint)>) -> Self {
-        Self { lints }
+        Self {
+            lints,
+            ..Default::default()
+        }
+    }
+
+    /// Set the configuration passed to configurable lints (default: [`LintConfig::default`])
+    pub fn with_config(mut self, config: LintConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Return the configuration passed to configurable lints
+    pub fn config(&self) -> &LintConfig {
+        &self.config
     }
 
     /// Return a iterator on the registered lint definitions and functions
@@ -80,14 +142,34 @@ impl<'a> CRLLintRegistry<'a> {
         self.lints.iter()
     }
 
+    /// Return a iterator on the registered configurable lint definitions and functions
+    pub fn configurable_lints(
+        &self,
+    ) -> impl Iterator<Item = &(LintDefinition<'a>, ConfigurableCRLLint)> {
+        self.configurable_lints.iter()
+    }
+
     /// Register a new lint definition and function
     pub fn insert(&mut self, lint_definition: LintDefinition<'a>, lint: CRLLint) {
         self.lints.push((lint_definition, lint));
     }
 
+    /// Register a new lint definition and configurable function
+    pub fn insert_configurable(
+        &mut self,
+        lint_definition: LintDefinition<'a>,
+        lint: ConfigurableCRLLint,
+    ) {
+        self.configurable_lints.push((lint_definition, lint));
+    }
+
     /// Merge lints from registry `other` in the current registry
+    ///
+    /// The configuration of `other` is ignored.
     pub fn merge(&mut self, mut other: CRLLintRegistry<'a>) {
         self.lints.append(&mut other.lints);
+        self.configurable_lints
+            .append(&mut other.configurable_lints);
     }
 
     /// Filter lints, keeping only lints with name matching the provided filter
@@ -95,17 +177,89 @@ impl<'a> CRLLintRegistry<'a> {
     /// Currently, the matching function is `starts_with`, and only one filter can be provided
     pub fn filter(&mut self, filter: &str) {
         self.lints.retain(|(def, _)| def.name.starts_with(filter));
+        self.configurable_lints
+            .retain(|(def, _)| def.name.starts_with(filter));
     }
 
     /// Run lint functions on the CRL, returning only the results of lints not returning `Pass`
     pub fn run_lints(
         &'a self,
         crl: &CertificateRevocationList,
+    ) -> Vec<(&'a LintDefinition<'a>, LintResult)> {
+        let results = self.lints.iter().filter_map(|(lint_definition, lint)| {
+            let r = (*lint)(crl);
+            match r.status {
+                LintStatus::Pass => None,
+                _ => Some((lint_definition, r)),
+            }
+        });
+        let configurable_results =
+            self.configurable_lints
+                .iter()
+                .filter_map(|(lint_definition, lint)| {
+                    let r = (*lint)(crl, &self.config);
+                    match r.status {
+                        LintStatus::Pass => None,
+                        _ => Some((lint_definition, r)),
+                    }
+                });
+        results.chain(configurable_results).collect()
+    }
+}
+
+impl<'a> FromIterator<(LintDefinition<'a>, CRLLint)> for CRLLintRegistry<'a> {
+    fn from_iter<T: IntoIterator<Item = (LintDefinition<'a>, CRLLint)>>(iter: T) -> Self {
+        let v = iter.into_iter().collect();
+        Self::new(v)
+    }
+}
+
+/// Registry containing lint functions checking a X.509 Certificate against a CRL
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct CertificateCRLLintRegistry<'a> {
+    lints: Vec<(LintDefinition<'a>, CertificateCRLLint)>,
+}
+
+impl<'a> CertificateCRLLintRegistry<'a> {
+    /// Build a new registry from provided lint definitions and functions
+    pub fn new(lints: Vec<(LintDefinition<'a>, CertificateCRLLint)>) -> Self {
+        Self { lints }
+    }
+
+    /// Return a iterator on the registered lint definitions and functions
+    pub fn lints(&self) -> impl Iterator<Item = &(LintDefinition<'a>, CertificateCRLLint)> {
+        self.lints.iter()
+    }
+
+    /// Register a new lint definition and function
+    pub fn insert(&mut self, lint_definition: LintDefinition<'a>, lint: CertificateCRLLint) {
+        self.lints.push((lint_definition, lint));
+    }
+
+    /// Merge lints from registry `other` in the current registry
+    pub fn merge(&mut self, mut other: CertificateCRLLintRegistry<'a>) {
+        self.lints.append(&mut other.lints);
+    }
+
+    /// Filter lints, keeping only lints with name matching the provided filter
+    ///
+    /// Currently, the matching function is `starts_with`, and only one filter can be provided
+    pub fn filter(&mut self, filter: &str) {
+        self.lints.retain(|(def, _)| def.name.starts_with(filter));
+    }
+
+    /// Run lint functions on the certificate and the CRL, returning only the results of lints
+    /// not returning `Pass`
+    pub fn run_lints(
+        &'a self,
+        x509: &X509Certificate,
+        crl: &CertificateRevocationList,
     ) -> Vec<(&'a LintDefinition<'a>, LintResult)> {
         self.lints
             .iter()
             .filter_map(|(lint_definition, lint)| {
-                let r = (*lint)(crl);
+                let r = (*lint)(x509, crl);
                 match r.status {
                     LintStatus::Pass => None,
                     _ => Some((lint_definition, r)),
@@ -115,8 +269,116 @@ impl<'a> CRLLintRegistry<'a> {
     }
 }
 
-impl<'a> FromIterator<(LintDefinition<'a>, CRLLint)> for CRLLintRegistry<'a> {
-    fn from_iter<T: IntoIterator<Item = (LintDefinition<'a>, CRLLint)>>(iter: T) -> Self {
+impl<'a> FromIterator<(LintDefinition<'a>, CertificateCRLLint)> for CertificateCRLLintRegistry<'a> {
+    fn from_iter<T: IntoIterator<Item = (LintDefinition<'a>, CertificateCRLLint)>>(
+        iter: T,
+    ) -> Self {
+        let v = iter.into_iter().collect();
+        Self::new(v)
+    }
+}
+
+/// Registry containing OCSP response lint functions
+#[allow(missing_debug_implementations)]
+#[derive(Default)]
+pub struct OcspLintRegistry<'a> {
+    lints: Vec<(LintDefinition<'a>, OcspLint)>,
+    configurable_lints: Vec<(LintDefinition<'a>, ConfigurableOcspLint)>,
+    config: LintConfig,
+}
+
+impl<'a> OcspLintRegistry<'a> {
+    /// Build a new registry from provided lint definitions and functions
+    pub fn new(lints: Vec<(LintDefinition<'a>, OcspLint)>) -> Self {
+        Self {
+            lints,
+            ..Default::default()
+        }
+    }
+
+    /// Set the configuration passed to configurable lints (default: [`LintConfig::default`])
+    pub fn with_config(mut self, config: LintConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Return the configuration passed to configurable lints
+    pub fn config(&self) -> &LintConfig {
+        &self.config
+    }
+
+    /// Return a iterator on the registered lint definitions and functions
+    pub fn lints(&self) -> impl Iterator<Item = &(LintDefinition<'a>, OcspLint)> {
+        self.lints.iter()
+    }
+
+    /// Return a iterator on the registered configurable lint definitions and functions
+    pub fn configurable_lints(
+        &self,
+    ) -> impl Iterator<Item = &(LintDefinition<'a>, ConfigurableOcspLint)> {
+        self.configurable_lints.iter()
+    }
+
+    /// Register a new lint definition and function
+    pub fn insert(&mut self, lint_definition: LintDefinition<'a>, lint: OcspLint) {
+        self.lints.push((lint_definition, lint));
+    }
+
+    /// Register a new lint definition and configurable function
+    pub fn insert_configurable(
+        &mut self,
+        lint_definition: LintDefinition<'a>,
+        lint: ConfigurableOcspLint,
+    ) {
+        self.configurable_lints.push((lint_definition, lint));
+    }
+
+    /// Merge lints from registry `other` in the current registry
+    ///
+    /// The configuration of `other` is ignored.
+    pub fn merge(&mut self, mut other: OcspLintRegistry<'a>) {
+        self.lints.append(&mut other.lints);
+        self.configurable_lints
+            .append(&mut other.configurable_lints);
+    }
+
+    /// Filter lints, keeping only lints with name matching the provided filter
+    ///
+    /// Currently, the matching function is `starts_with`, and only one filter can be provided
+    pub fn filter(&mut self, filter: &str) {
+        self.lints.retain(|(def, _)| def.name.starts_with(filter));
+        self.configurable_lints
+            .retain(|(def, _)| def.name.starts_with(filter));
+    }
+
+    /// Run lint functions on the OCSP response, returning only the results of lints not returning `Pass`
+    pub fn run_lints(
+        &'a self,
+        response: &OcspResponse,
+    ) -> Vec<(&'a LintDefinition<'a>, LintResult)> {
+        let results = self.lints.iter().filter_map(|(lint_definition, lint)| {
+            let r = (*lint)(response);
+            match r.status {
+                LintStatus::Pass => None,
+                _ => Some((lint_definition, r)),
+            }
+        });
+        let configurable_results =
+            self.configurable_lints
+                .iter()
+                .filter_map(|(lint_definition, lint)| {
+                    let r = (*lint)(response, &self.config);
+                    match r.status {
+                        LintStatus::Pass => None,
+                        _ => Some((lint_definition, r)),
+                    }
+                });
+        results.chain(configurable_results).collect()
+    }
+}
+
+impl<'a> FromIterator<(LintDefinition<'a>, OcspLint)> for OcspLintRegistry<'a> {
+    fn from_iter<T: IntoIterator<Item = (LintDefinition<'a>, OcspLint)>>(iter: T) -> Self {
         let v = iter.into_iter().collect();
         Self::new(v)
     }
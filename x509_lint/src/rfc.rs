@@ -1,28 +1,120 @@
-use crate::CRLLintRegistry;
+use crate::{CRLLintRegistry, CertificateCRLLintRegistry, OcspLintRegistry};
 
 use super::{CertificateLint, CertificateLintRegistry, LintDefinition, LintResult, LintStatus};
 
+mod cert_crl;
+mod crl_distribution_points;
+mod crl_number;
+mod crl_validity;
+#[cfg(feature = "ec-validation")]
+mod ec_validation;
+mod eku;
 mod extensions;
+mod helpers;
+mod info_access;
+mod issuing_distribution_point;
+mod key_identifiers;
+mod key_quality;
 mod name;
+mod name_constraints;
+mod ocsp;
+#[rustfmt::skip]
+mod oids;
+mod policies;
+mod pqc;
+mod revoked;
 mod rfc5280;
+mod san;
+mod serial;
+mod signature;
+mod spki;
+#[cfg(test)]
+pub(crate) mod testing;
+mod time_encoding;
+mod weak_algorithms;
 
 /// Return a [`CertificateLintRegistry`] containing all RFC lints included in this crate
 /// for X.509 Certificates
 pub fn rfc_lints<'a>() -> CertificateLintRegistry<'a> {
     let all_rfc_lints = [
         rfc5280::RFC_LINTS,
+        serial::SERIAL_LINTS,
+        time_encoding::TIME_ENCODING_LINTS,
+        signature::SIGNATURE_LINTS,
+        spki::SPKI_LINTS,
+        #[cfg(feature = "ec-validation")]
+        ec_validation::EC_VALIDATION_LINTS,
+        key_quality::KEY_QUALITY_LINTS,
+        pqc::PQC_LINTS,
         name::NAME_LINTS,
         extensions::EXTENSION_LINTS,
+        key_identifiers::KEY_IDENTIFIER_LINTS,
+        san::SAN_LINTS,
+        name_constraints::NAME_CONSTRAINTS_LINTS,
+        policies::POLICIES_LINTS,
+        eku::EKU_LINTS,
+        crl_distribution_points::CRL_DISTRIBUTION_POINTS_LINTS,
+        info_access::INFO_ACCESS_LINTS,
     ]
     .concat();
-    CertificateLintRegistry::new(all_rfc_lints)
+    let mut registry = CertificateLintRegistry::new(all_rfc_lints);
+    let configurable_lints = [
+        spki::SPKI_CONFIGURABLE_LINTS,
+        key_quality::KEY_QUALITY_CONFIGURABLE_LINTS,
+        weak_algorithms::WEAK_ALGORITHM_CONFIGURABLE_LINTS,
+        extensions::EXTENSION_CONFIGURABLE_LINTS,
+    ];
+    for (definition, lint) in configurable_lints.concat() {
+        registry.insert_configurable(definition, lint);
+    }
+    registry
 }
 
 /// Return a [`CRLLintRegistry`] containing all RFC lints included in this crate
 /// for X.509 Certificate Revocation List (CRL)
 pub fn crl_rfc_lints<'a>() -> CRLLintRegistry<'a> {
-    let all_rfc_lints = [rfc5280::CRL_RFC_LINTS, extensions::CRL_EXTENSION_LINTS].concat();
-    CRLLintRegistry::new(all_rfc_lints)
+    let all_rfc_lints = [
+        rfc5280::CRL_RFC_LINTS,
+        crl_validity::CRL_VALIDITY_LINTS,
+        crl_number::CRL_NUMBER_LINTS,
+        issuing_distribution_point::CRL_IDP_LINTS,
+        name::CRL_NAME_LINTS,
+        serial::CRL_SERIAL_LINTS,
+        revoked::CRL_REVOKED_LINTS,
+        time_encoding::CRL_TIME_ENCODING_LINTS,
+        signature::CRL_SIGNATURE_LINTS,
+        pqc::CRL_PQC_LINTS,
+        extensions::CRL_EXTENSION_LINTS,
+        key_identifiers::CRL_KEY_IDENTIFIER_LINTS,
+        crl_distribution_points::CRL_FRESHEST_CRL_LINTS,
+    ]
+    .concat();
+    let mut registry = CRLLintRegistry::new(all_rfc_lints);
+    let configurable_lints = [
+        crl_validity::CRL_VALIDITY_CONFIGURABLE_LINTS,
+        weak_algorithms::CRL_WEAK_ALGORITHM_CONFIGURABLE_LINTS,
+        extensions::CRL_EXTENSION_CONFIGURABLE_LINTS,
+    ];
+    for (definition, lint) in configurable_lints.concat() {
+        registry.insert_configurable(definition, lint);
+    }
+    registry
+}
+
+/// Return a [`CertificateCRLLintRegistry`] containing all RFC lints included in this crate
+/// checking the consistency of a X.509 Certificate and a CRL
+pub fn cert_crl_rfc_lints<'a>() -> CertificateCRLLintRegistry<'a> {
+    CertificateCRLLintRegistry::new(cert_crl::CERT_CRL_LINTS.to_vec())
+}
+
+/// Return a [`OcspLintRegistry`] containing all RFC lints included in this crate
+/// for OCSP responses
+pub fn ocsp_rfc_lints<'a>() -> OcspLintRegistry<'a> {
+    let mut registry = OcspLintRegistry::new(ocsp::OCSP_LINTS.to_vec());
+    for (definition, lint) in ocsp::OCSP_CONFIGURABLE_LINTS.to_vec() {
+        registry.insert_configurable(definition, lint);
+    }
+    registry
 }
 
 #[cfg(test)]
@@ -0,0 +1,347 @@
+//! Consistency of a certificate and the CRL used to check its revocation status
+
+use x509_parser::asn1_rs::{Any, Class, FromDer};
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+
+use super::crl_distribution_points::full_name_uris;
+use super::helpers::der_items;
+use super::issuing_distribution_point::issuing_distribution_point;
+use crate::*;
+
+pub(crate) const CERT_CRL_LINTS: &[(LintDefinition, CertificateCRLLint)] = &[
+    (CERT_CRL_ISSUER_MISMATCH, cert_crl_issuer_mismatch),
+    (CERT_CRL_IDP_MISMATCH, cert_crl_idp_mismatch),
+    (CERT_CRL_AKI_MISMATCH, cert_crl_aki_mismatch),
+    (CERT_CRL_SCOPE_MISMATCH, cert_crl_scope_mismatch),
+    (CERT_CRL_REVOKED, cert_crl_revoked),
+];
+
+/// Return the distribution points of the CRLDistributionPoints extension
+fn crl_distribution_points<'a>(x509: &'a X509Certificate) -> Vec<CRLDistributionPoint<'a>> {
+    x509.extensions()
+        .iter()
+        .filter_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::CRLDistributionPoints(dp) => Some(dp.points.clone()),
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Return the keyIdentifier of the AuthorityKeyIdentifier extension
+fn aki_key_identifier<'a>(extensions: &'a [X509Extension]) -> Option<&'a KeyIdentifier<'a>> {
+    extensions
+        .iter()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::AuthorityKeyIdentifier(aki) => aki.key_identifier.as_ref(),
+            _ => None,
+        })
+}
+
+/// Return true if the GeneralNames `value` contain a directoryName equal to `name` (raw DER)
+///
+/// The certificateIssuer CRL entry extension is not parsed by `x509_parser`.
+fn general_names_contain_directory_name(value: &[u8], name: &[u8]) -> bool {
+    let Ok((_, names)) = Any::from_der(value) else {
+        return false;
+    };
+    der_items(names.data)
+        .any(|gn| gn.class() == Class::ContextSpecific && gn.tag().0 == 4 && gn.data == name)
+}
+
+lint_definition!(
+    CERT_CRL_ISSUER_MISMATCH,
+    "rfc:cert_crl_issuer_mismatch",
+    "CRL issuer does not match the certificate issuer or the cRLIssuer of its distribution points",
+    "RFC5280: 6.3.3"
+);
+pub(super) fn cert_crl_issuer_mismatch(
+    x509: &X509Certificate,
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    let crl_issuer = crl.issuer().as_raw();
+    if crl_issuer == x509.issuer().as_raw() {
+        return LintResult::pass();
+    }
+    let in_crl_issuer = crl_distribution_points(x509)
+        .iter()
+        .filter_map(|dp| dp.crl_issuer.as_ref())
+        .flatten()
+        .any(|name| matches!(name, GeneralName::DirectoryName(n) if n.as_raw() == crl_issuer));
+    if in_crl_issuer {
+        return LintResult::pass();
+    }
+    LintResult::new_details(
+        LintStatus::Error,
+        LintDetails::new(format!("CRL issuer: {}", crl.issuer())),
+    )
+}
+
+lint_definition!(
+    CERT_CRL_IDP_MISMATCH,
+    "rfc:cert_crl_idp_mismatch",
+    "CRL issuingDistributionPoint does not match any CRLDistributionPoints URI of the certificate",
+    "RFC5280: 6.3.3"
+);
+pub(super) fn cert_crl_idp_mismatch(
+    x509: &X509Certificate,
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    let Some(IssuingDistributionPoint {
+        distribution_point: Some(DistributionPointName::FullName(names)),
+        ..
+    }) = issuing_distribution_point(crl)
+    else {
+        return LintResult::pass();
+    };
+    let idp_uris: Vec<_> = names
+        .iter()
+        .filter_map(|name| match name {
+            GeneralName::URI(uri) => Some(*uri),
+            _ => None,
+        })
+        .collect();
+    let points = crl_distribution_points(x509);
+    let cert_uris: Vec<_> = full_name_uris(&points).collect();
+    // only URIs are compared
+    if idp_uris.is_empty() || cert_uris.is_empty() {
+        return LintResult::pass();
+    }
+    if !idp_uris.iter().any(|uri| cert_uris.contains(uri)) {
+        let details = LintDetails::new(format!("CRL distribution point: {}", idp_uris.join(", ")));
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_CRL_AKI_MISMATCH,
+    "rfc:cert_crl_aki_mismatch",
+    "CRL AuthorityKeyIdentifier does not match the certificate AuthorityKeyIdentifier",
+    "RFC5280: 5.2.1"
+);
+pub(super) fn cert_crl_aki_mismatch(
+    x509: &X509Certificate,
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    match (
+        aki_key_identifier(x509.extensions()),
+        aki_key_identifier(crl.extensions()),
+    ) {
+        (Some(cert_key_id), Some(crl_key_id)) if cert_key_id != crl_key_id => {
+            let details =
+                LintDetails::new(format!("certificate: {cert_key_id:x}, CRL: {crl_key_id:x}"));
+            LintResult::new_details(LintStatus::Error, details)
+        }
+        _ => LintResult::pass(),
+    }
+}
+
+lint_definition!(
+    CERT_CRL_SCOPE_MISMATCH,
+    "rfc:cert_crl_scope_mismatch",
+    "CRL scope (issuingDistributionPoint) does not cover the certificate type",
+    "RFC5280: 6.3.3"
+);
+pub(super) fn cert_crl_scope_mismatch(
+    x509: &X509Certificate,
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    let Some(idp) = issuing_distribution_point(crl) else {
+        return LintResult::pass();
+    };
+    let details = if idp.only_contains_user_certs && x509.is_ca() {
+        "onlyContainsUserCerts, certificate is a CA"
+    } else if idp.only_contains_ca_certs && !x509.is_ca() {
+        "onlyContainsCACerts, certificate is not a CA"
+    } else if idp.only_contains_attribute_certs {
+        "onlyContainsAttributeCerts"
+    } else {
+        return LintResult::pass();
+    };
+    LintResult::new_details(LintStatus::Error, details.into())
+}
+
+lint_definition!(
+    CERT_CRL_REVOKED,
+    "rfc:cert_crl_revoked",
+    "Certificate is listed as revoked in the CRL",
+    "RFC5280: 6.3.3"
+);
+pub(super) fn cert_crl_revoked(
+    x509: &X509Certificate,
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    let cert_issuer = x509.issuer().as_raw();
+    // entries belong to the CRL issuer, until a certificateIssuer extension (indirect CRLs)
+    let mut same_issuer = crl.issuer().as_raw() == cert_issuer;
+    for revoked in crl.iter_revoked_certificates() {
+        if let Some(ext) = revoked.find_extension(&OID_X509_EXT_ISSUER) {
+            same_issuer = general_names_contain_directory_name(ext.value, cert_issuer);
+        }
+        if same_issuer && revoked.raw_serial() == x509.raw_serial() {
+            let mut details = format!("revoked on {}", revoked.revocation_date);
+            if let Some((_, reason)) = revoked.reason_code() {
+                details += &format!(", reason: {reason}");
+            }
+            return LintResult::new_details(LintStatus::Error, LintDetails::new(details));
+        }
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn failures(cert: CertificateBuilder, crl: CrlBuilder) -> Vec<String> {
+        let cert_data = cert.build();
+        let crl_data = crl.build();
+        let (_, x509) = X509Certificate::from_der(&cert_data).unwrap();
+        let (_, crl) = CertificateRevocationList::from_der(&crl_data).unwrap();
+        cert_crl_lint_failures(CERT_CRL_LINTS, &x509, &crl)
+    }
+
+    fn crldp_ext(uri: &str, crl_issuer: Option<&str>) -> Vec<u8> {
+        let full_name = der(0xa0, &der(0xa0, &der(0x86, uri.as_bytes())));
+        let point = match crl_issuer {
+            Some(issuer) => seq(&[&full_name, &der(0xa2, &der(0xa4, &name(issuer)))]),
+            None => seq(&[&full_name]),
+        };
+        extension(
+            &OID_X509_EXT_CRL_DISTRIBUTION_POINTS,
+            false,
+            &seq(&[&point]),
+        )
+    }
+
+    fn idp_ext(uri: &str, scope: &[u8]) -> Vec<u8> {
+        let full_name = der(0xa0, &der(0xa0, &der(0x86, uri.as_bytes())));
+        extension(
+            &OID_X509_EXT_ISSUER_DISTRIBUTION_POINT,
+            true,
+            &seq(&[&full_name, scope]),
+        )
+    }
+
+    fn authority_key_identifier(key_id: &[u8]) -> Vec<u8> {
+        extension(
+            &OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER,
+            false,
+            &seq(&[&der(0x80, key_id)]),
+        )
+    }
+
+    fn certificate_issuer(issuer: &str) -> Vec<u8> {
+        extension(
+            &OID_X509_EXT_ISSUER,
+            true,
+            &seq(&[&der(0xa4, &name(issuer))]),
+        )
+    }
+
+    const URI: &str = "http://crl.example.com/ca.crl";
+
+    #[test]
+    fn matching_cert_crl() {
+        let cert = CertificateBuilder::with_extensions(vec![
+            authority_key_identifier(&[1; 20]),
+            crldp_ext(URI, None),
+        ]);
+        let crl = CrlBuilder {
+            revoked: vec![revoked_entry(&[1], &[])],
+            extensions: vec![
+                authority_key_identifier(&[1; 20]),
+                idp_ext(URI, &der(0x81, &[0xff])),
+            ],
+            ..Default::default()
+        };
+        assert!(failures(cert, crl).is_empty());
+
+        // CRL issued by the cRLIssuer of the distribution point
+        let cert = CertificateBuilder::with_extensions(vec![crldp_ext(URI, Some("CRL Issuer"))]);
+        let crl = CrlBuilder {
+            issuer: name("CRL Issuer"),
+            ..Default::default()
+        };
+        assert!(failures(cert, crl).is_empty());
+
+        // CA certificate and CRL for CA certificates
+        let cert = CertificateBuilder::with_extensions(vec![basic_constraints(true)]);
+        let crl = CrlBuilder::with_extensions(vec![idp_ext(URI, &der(0x82, &[0xff]))]);
+        assert!(failures(cert, crl).is_empty());
+    }
+
+    #[test]
+    fn mismatching_cert_crl() {
+        let cert = CertificateBuilder::with_extensions(vec![
+            authority_key_identifier(&[1; 20]),
+            crldp_ext(URI, None),
+        ]);
+        let crl = CrlBuilder {
+            issuer: name("Other CA"),
+            extensions: vec![
+                authority_key_identifier(&[2; 20]),
+                idp_ext("http://crl.example.com/other.crl", &der(0x82, &[0xff])),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(cert, crl),
+            [
+                "rfc:cert_crl_issuer_mismatch",
+                "rfc:cert_crl_idp_mismatch",
+                "rfc:cert_crl_aki_mismatch",
+                "rfc:cert_crl_scope_mismatch",
+            ]
+        );
+
+        let cert = CertificateBuilder::with_extensions(vec![basic_constraints(true)]);
+        let crl = CrlBuilder::with_extensions(vec![idp_ext(URI, &der(0x81, &[0xff]))]);
+        assert_eq!(failures(cert, crl), ["rfc:cert_crl_scope_mismatch"]);
+    }
+
+    #[test]
+    fn revoked_certificate() {
+        let serial = CertificateBuilder::default().serial;
+        let crl = CrlBuilder {
+            revoked: vec![revoked_entry(&[1], &[]), revoked_entry(&serial, &[])],
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(CertificateBuilder::default(), crl),
+            ["rfc:cert_crl_revoked"]
+        );
+
+        // indirect CRL: the entry belongs to another issuer
+        let crl = CrlBuilder {
+            revoked: vec![revoked_entry(&serial, &[certificate_issuer("Other CA")])],
+            ..Default::default()
+        };
+        assert!(failures(CertificateBuilder::default(), crl).is_empty());
+
+        // indirect CRL: the certificateIssuer applies to the following entries
+        let cert = CertificateBuilder::with_extensions(vec![crldp_ext(URI, Some("Other CA"))]);
+        let crl = CrlBuilder {
+            issuer: name("Other CA"),
+            revoked: vec![
+                revoked_entry(&[1], &[certificate_issuer("Test CA")]),
+                revoked_entry(&serial, &[]),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(failures(cert, crl), ["rfc:cert_crl_revoked"]);
+
+        // entries of the CRL issuer do not apply to certificates of other issuers
+        let cert = CertificateBuilder::with_extensions(vec![crldp_ext(URI, Some("Other CA"))]);
+        let crl = CrlBuilder {
+            issuer: name("Other CA"),
+            revoked: vec![revoked_entry(&serial, &[])],
+            ..Default::default()
+        };
+        assert!(failures(cert, crl).is_empty());
+    }
+}
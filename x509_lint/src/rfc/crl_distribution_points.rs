@@ -0,0 +1,318 @@
+use x509_parser::asn1_rs::{Any, Class};
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::{
+    OID_X509_EXT_CRL_DISTRIBUTION_POINTS, OID_X509_EXT_DELTA_CRL_INDICATOR,
+    OID_X509_EXT_FRESHEST_CRL,
+};
+use x509_parser::prelude::{CertificateRevocationList, FromDer, X509Certificate};
+
+use super::helpers::{check_uri, der_items};
+use crate::*;
+
+pub(crate) const CRL_DISTRIBUTION_POINTS_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_CRLDP_CRITICAL, cert_ext_crldp_critical),
+    (CERT_EXT_CRLDP_REASONS_ONLY, cert_ext_crldp_reasons_only),
+    (CERT_EXT_CRLDP_URI_INVALID, cert_ext_crldp_uri_invalid),
+    (
+        CERT_EXT_CRLDP_URI_UNSUPPORTED_SCHEME,
+        cert_ext_crldp_uri_unsupported_scheme,
+    ),
+    (
+        CERT_EXT_CRLDP_RELATIVE_NAME_MULTIPLE_ISSUERS,
+        cert_ext_crldp_relative_name_multiple_issuers,
+    ),
+    (
+        CERT_EXT_FRESHEST_CRL_CRITICAL,
+        cert_ext_freshest_crl_critical,
+    ),
+];
+
+pub(crate) const CRL_FRESHEST_CRL_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_EXT_FRESHEST_CRL_CRITICAL, crl_ext_freshest_crl_critical),
+    (CRL_EXT_FRESHEST_CRL_IN_DELTA, crl_ext_freshest_crl_in_delta),
+];
+
+/// URI schemes expected for CRL distribution points
+const SUPPORTED_SCHEMES: &[&str] = &["http", "https", "ldap", "ftp"];
+
+/// Return all distribution points from the CRLDistributionPoints and FreshestCRL extensions
+///
+/// FreshestCRL uses the same syntax, but is not parsed by `x509_parser`.
+fn distribution_points<'a>(x509: &'a X509Certificate) -> Vec<CRLDistributionPoint<'a>> {
+    let mut points = Vec::new();
+    for ext in x509.extensions() {
+        match ext.parsed_extension() {
+            ParsedExtension::CRLDistributionPoints(dp) => points.extend(dp.iter().cloned()),
+            ParsedExtension::UnsupportedExtension { oid } if *oid == OID_X509_EXT_FRESHEST_CRL => {
+                if let Ok((_, dp)) = CRLDistributionPoints::from_der(ext.value) {
+                    points.extend(dp.points);
+                }
+            }
+            _ => (),
+        }
+    }
+    points
+}
+
+/// Return the fields of all DistributionPoints of the CRLDistributionPoints and FreshestCRL
+/// extensions, read from the raw value
+///
+/// `x509_parser` expects an explicit tag for nameRelativeToCRLIssuer, and returns an empty
+/// DistributionPoint when it is encoded as specified in RFC5280 (implicit tag).
+fn raw_distribution_points<'a>(x509: &'a X509Certificate) -> Vec<Vec<Any<'a>>> {
+    x509.iter_extensions()
+        .filter(|ext| {
+            ext.oid == OID_X509_EXT_CRL_DISTRIBUTION_POINTS || ext.oid == OID_X509_EXT_FRESHEST_CRL
+        })
+        .filter_map(|ext| Any::from_der(ext.value).ok())
+        .flat_map(|(_, points)| der_items(points.data))
+        .map(|point| der_items(point.data).collect())
+        .collect()
+}
+
+/// Return the field with the context-specific tag `tag`
+fn context_field<'a, 'b>(fields: &'b [Any<'a>], tag: u32) -> Option<&'b Any<'a>> {
+    fields
+        .iter()
+        .find(|field| field.class() == Class::ContextSpecific && field.tag().0 == tag)
+}
+
+/// Iterate on the URIs of the fullName of distribution points
+pub(super) fn full_name_uris<'a>(
+    points: &'a [CRLDistributionPoint<'a>],
+) -> impl Iterator<Item = &'a str> {
+    points
+        .iter()
+        .filter_map(|dp| match &dp.distribution_point {
+            Some(DistributionPointName::FullName(names)) => Some(names.iter()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|name| match name {
+            GeneralName::URI(uri) => Some(*uri),
+            _ => None,
+        })
+}
+
+lint_definition!(
+    CERT_EXT_CRLDP_CRITICAL,
+    "rfc:cert_ext_crldp_critical",
+    "The CRLDistributionPoints extension SHOULD be non-critical",
+    "RFC5280: 4.2.1.13"
+);
+certificate_lint!(
+    pub(super) cert_ext_crldp_critical,
+    LintStatus::Warn,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_CRL_DISTRIBUTION_POINTS && ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_CRLDP_REASONS_ONLY,
+    "rfc:cert_ext_crldp_reasons_only",
+    "A DistributionPoint MUST NOT consist of only the reasons field",
+    "RFC5280: 4.2.1.13"
+);
+certificate_lint!(
+    pub(super) cert_ext_crldp_reasons_only,
+    LintStatus::Error,
+    |x509: &X509Certificate| raw_distribution_points(x509)
+        .iter()
+        .any(|fields| context_field(fields, 0).is_none() && context_field(fields, 2).is_none())
+);
+
+lint_definition!(
+    CERT_EXT_CRLDP_URI_INVALID,
+    "rfc:cert_ext_crldp_uri_invalid",
+    "Invalid URI in DistributionPoint fullName",
+    "RFC5280: 4.2.1.13"
+);
+pub(super) fn cert_ext_crldp_uri_invalid(x509: &X509Certificate) -> LintResult {
+    let points = distribution_points(x509);
+    for uri in full_name_uris(&points) {
+        // for LDAP, the host is only recommended
+        if uri.starts_with("ldap:///") {
+            continue;
+        }
+        if let Err(e) = check_uri(uri) {
+            let details = LintDetails::new(format!("'{uri}': {e}"));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_CRLDP_URI_UNSUPPORTED_SCHEME,
+    "rfc:cert_ext_crldp_uri_unsupported_scheme",
+    "DistributionPoint URI should use the HTTP or LDAP scheme",
+    "RFC5280: 4.2.1.13"
+);
+pub(super) fn cert_ext_crldp_uri_unsupported_scheme(x509: &X509Certificate) -> LintResult {
+    let points = distribution_points(x509);
+    for uri in full_name_uris(&points) {
+        let scheme = uri.split_once(':').map_or("", |(scheme, _)| scheme);
+        if !SUPPORTED_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()) {
+            let details = LintDetails::new(format!("'{uri}'"));
+            return LintResult::new_details(LintStatus::Warn, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_CRLDP_RELATIVE_NAME_MULTIPLE_ISSUERS,
+    "rfc:cert_ext_crldp_relative_name_multiple_issuers",
+    "nameRelativeToCRLIssuer MUST NOT be used when cRLIssuer contains more than one distinguished name",
+    "RFC5280: 4.2.1.13"
+);
+certificate_lint!(
+    pub(super) cert_ext_crldp_relative_name_multiple_issuers,
+    LintStatus::Error,
+    |x509: &X509Certificate| raw_distribution_points(x509).iter().any(|fields| {
+        // DistributionPointName is a CHOICE, so [0] is an explicit tag
+        let relative_name = context_field(fields, 0)
+            .and_then(|dp| der_items(dp.data).next())
+            .is_some_and(|name| name.class() == Class::ContextSpecific && name.tag().0 == 1);
+        let directory_names = context_field(fields, 2).map_or(0, |issuers| {
+            der_items(issuers.data)
+                .filter(|name| name.class() == Class::ContextSpecific && name.tag().0 == 4)
+                .count()
+        });
+        relative_name && directory_names > 1
+    })
+);
+
+lint_definition!(
+    CERT_EXT_FRESHEST_CRL_CRITICAL,
+    "rfc:cert_ext_freshest_crl_critical",
+    "The FreshestCRL extension MUST be marked as non-critical",
+    "RFC5280: 4.2.1.15"
+);
+certificate_lint!(
+    pub(super) cert_ext_freshest_crl_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_FRESHEST_CRL && ext.critical)
+);
+
+lint_definition!(
+    CRL_EXT_FRESHEST_CRL_CRITICAL,
+    "rfc:crl_ext_freshest_crl_critical",
+    "The FreshestCRL extension MUST be marked as non-critical",
+    "RFC5280: 5.2.6"
+);
+crl_lint!(
+    pub(super) crl_ext_freshest_crl_critical,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| crl
+        .extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_FRESHEST_CRL && ext.critical)
+);
+
+lint_definition!(
+    CRL_EXT_FRESHEST_CRL_IN_DELTA,
+    "rfc:crl_ext_freshest_crl_in_delta",
+    "The FreshestCRL extension MUST NOT appear in delta CRLs",
+    "RFC5280: 5.2.6"
+);
+crl_lint!(
+    pub(super) crl_ext_freshest_crl_in_delta,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| {
+        let has_ext = |oid| crl.extensions().iter().any(|ext| ext.oid == oid);
+        has_ext(OID_X509_EXT_DELTA_CRL_INDICATOR) && has_ext(OID_X509_EXT_FRESHEST_CRL)
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::OID_X509_COMMON_NAME;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    /// DistributionPoint with a fullName URI
+    fn uri_point(uri: &str) -> Vec<u8> {
+        seq(&[&der(0xa0, &der(0xa0, &der(0x86, uri.as_bytes())))])
+    }
+
+    #[test]
+    fn crldp_lints() {
+        let crldp = seq(&[&uri_point("http://crl.example.com/ca.crl")]);
+        let freshest = seq(&[&uri_point(
+            "ldap://ldap.example.com/cn=CA?deltaRevocationList",
+        )]);
+        let data = CertificateBuilder::with_extensions(vec![
+            extension(&OID_X509_EXT_CRL_DISTRIBUTION_POINTS, false, &crldp),
+            extension(&OID_X509_EXT_FRESHEST_CRL, false, &freshest),
+        ])
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(CRL_DISTRIBUTION_POINTS_LINTS, &x509).is_empty());
+
+        // reasons only, nameRelativeToCRLIssuer with two cRLIssuer names, invalid URIs
+        let reasons_only = seq(&[&der(0x81, &[1, 0x40])]);
+        let cn = seq(&[&oid(&OID_X509_COMMON_NAME), &der(0x0c, b"CRL1")]);
+        let relative_name = seq(&[
+            &der(0xa0, &der(0xa1, &cn)),
+            &der(
+                0xa2,
+                &[der(0xa4, &name("CA 1")), der(0xa4, &name("CA 2"))].concat(),
+            ),
+        ]);
+        let crldp = seq(&[
+            &reasons_only,
+            &relative_name,
+            &uri_point("http:///ca.crl"),
+            &uri_point("file://crl.example.com/ca.crl"),
+        ]);
+        let data = CertificateBuilder::with_extensions(vec![
+            extension(&OID_X509_EXT_CRL_DISTRIBUTION_POINTS, true, &crldp),
+            extension(&OID_X509_EXT_FRESHEST_CRL, true, &freshest),
+        ])
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(CRL_DISTRIBUTION_POINTS_LINTS, &x509),
+            [
+                "rfc:cert_ext_crldp_critical",
+                "rfc:cert_ext_crldp_reasons_only",
+                "rfc:cert_ext_crldp_uri_invalid",
+                "rfc:cert_ext_crldp_uri_unsupported_scheme",
+                "rfc:cert_ext_crldp_relative_name_multiple_issuers",
+                "rfc:cert_ext_freshest_crl_critical",
+            ]
+        );
+    }
+
+    #[test]
+    fn crl_freshest_crl_lints() {
+        let freshest = seq(&[&uri_point("http://crl.example.com/delta.crl")]);
+        let data = CrlBuilder::with_extensions(vec![extension(
+            &OID_X509_EXT_FRESHEST_CRL,
+            false,
+            &freshest,
+        )])
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert!(crl_lint_failures(CRL_FRESHEST_CRL_LINTS, &crl).is_empty());
+
+        let data = CrlBuilder::with_extensions(vec![
+            extension(&OID_X509_EXT_DELTA_CRL_INDICATOR, true, &int(&[1])),
+            extension(&OID_X509_EXT_FRESHEST_CRL, true, &freshest),
+        ])
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert_eq!(
+            crl_lint_failures(CRL_FRESHEST_CRL_LINTS, &crl),
+            [
+                "rfc:crl_ext_freshest_crl_critical",
+                "rfc:crl_ext_freshest_crl_in_delta",
+            ]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+//! cRLNumber and deltaCRLIndicator lints (RFC5280 5.2.3 and 5.2.4)
+//!
+//! There is no lint checking that complete CRLs do not contain deltaCRLIndicator: a CRL
+//! containing this extension is a delta CRL by definition. Delta CRLs are checked by the
+//! lints below, and by `crl_ext_freshest_crl_in_delta`.
+
+use x509_parser::asn1_rs::{Any, FromDer, Oid, Tag};
+use x509_parser::num_bigint::BigUint;
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::CertificateRevocationList;
+
+use crate::*;
+
+pub(crate) const CRL_NUMBER_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_NUMBER_MISSING, crl_number_missing),
+    (CRL_NUMBER_CRITICAL, crl_number_critical),
+    (CRL_NUMBER_NEGATIVE, crl_number_negative),
+    (CRL_NUMBER_TOO_LONG, crl_number_too_long),
+    (
+        CRL_DELTA_INDICATOR_NOT_CRITICAL,
+        crl_delta_indicator_not_critical,
+    ),
+    (CRL_DELTA_BASE_NOT_LOWER, crl_delta_base_not_lower),
+];
+
+/// Return the raw INTEGER content of the first extension with the given OID
+///
+/// The raw value is used (instead of the value parsed by `x509_parser`) to check the sign
+/// and the length of the integer. deltaCRLIndicator is not parsed by `x509_parser`.
+fn integer_extension<'a>(crl: &'a CertificateRevocationList, oid: &Oid) -> Option<&'a [u8]> {
+    let ext = crl.extensions().iter().find(|ext| ext.oid == *oid)?;
+    let (_, value) = Any::from_der(ext.value).ok()?;
+    (value.tag() == Tag::Integer).then_some(value.data)
+}
+
+/// Return the cRLNumber and BaseCRLNumber (from deltaCRLIndicator), if present
+fn crl_numbers<'a>(crl: &'a CertificateRevocationList) -> [(&'static str, Option<&'a [u8]>); 2] {
+    let number = integer_extension(crl, &OID_X509_EXT_CRL_NUMBER);
+    let base = integer_extension(crl, &OID_X509_EXT_DELTA_CRL_INDICATOR);
+    [("cRLNumber", number), ("BaseCRLNumber", base)]
+}
+
+lint_definition!(
+    CRL_NUMBER_MISSING,
+    "rfc:crl_number_missing",
+    "CRL issuers MUST include the cRLNumber extension in all CRLs",
+    "RFC5280: 5.2.3"
+);
+crl_lint!(
+    pub(super) crl_number_missing,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| !crl
+        .extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_CRL_NUMBER)
+);
+
+lint_definition!(
+    CRL_NUMBER_CRITICAL,
+    "rfc:crl_number_critical",
+    "The cRLNumber extension MUST be marked non-critical",
+    "RFC5280: 5.2.3"
+);
+crl_lint!(
+    pub(super) crl_number_critical,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| crl
+        .extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_CRL_NUMBER && ext.critical)
+);
+
+lint_definition!(
+    CRL_NUMBER_NEGATIVE,
+    "rfc:crl_number_negative",
+    "CRL numbers MUST be non-negative integers",
+    "RFC5280: 5.2.3"
+);
+pub(super) fn crl_number_negative(crl: &CertificateRevocationList) -> LintResult {
+    for (label, value) in crl_numbers(crl) {
+        if value.is_some_and(|v| v.first().is_some_and(|b| b & 0x80 != 0)) {
+            return LintResult::new_details(LintStatus::Error, LintDetails::new(label.into()));
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_NUMBER_TOO_LONG,
+    "rfc:crl_number_too_long",
+    "CRL issuers MUST NOT use CRL numbers longer than 20 octets",
+    "RFC5280: 5.2.3"
+);
+pub(super) fn crl_number_too_long(crl: &CertificateRevocationList) -> LintResult {
+    for (label, value) in crl_numbers(crl) {
+        if let Some(v) = value.filter(|v| v.len() > 20) {
+            let details = LintDetails::new(format!("{label}: {} octets", v.len()));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_DELTA_INDICATOR_NOT_CRITICAL,
+    "rfc:crl_delta_indicator_not_critical",
+    "The deltaCRLIndicator extension MUST be marked critical",
+    "RFC5280: 5.2.4"
+);
+crl_lint!(
+    pub(super) crl_delta_indicator_not_critical,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| crl
+        .extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_DELTA_CRL_INDICATOR && !ext.critical)
+);
+
+lint_definition!(
+    CRL_DELTA_BASE_NOT_LOWER,
+    "rfc:crl_delta_base_not_lower",
+    "The BaseCRLNumber of a delta CRL MUST be lower than its cRLNumber",
+    "RFC5280: 5.2.4"
+);
+pub(super) fn crl_delta_base_not_lower(crl: &CertificateRevocationList) -> LintResult {
+    let [(_, Some(number)), (_, Some(base))] = crl_numbers(crl) else {
+        return LintResult::pass();
+    };
+    let (number, base) = (BigUint::from_bytes_be(number), BigUint::from_bytes_be(base));
+    if base >= number {
+        let details = LintDetails::new(format!("BaseCRLNumber {base}, cRLNumber {number}"));
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rfc::testing::*;
+
+    #[test]
+    fn crl_number_lints() {
+        let crl_number = extension(&OID_X509_EXT_CRL_NUMBER, false, &int(&[0x10]));
+        let data = CrlBuilder::with_extensions(vec![crl_number.clone()]).build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert!(crl_lint_failures(CRL_NUMBER_LINTS, &crl).is_empty());
+
+        let data = CrlBuilder::default().build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert_eq!(
+            crl_lint_failures(CRL_NUMBER_LINTS, &crl),
+            ["rfc:crl_number_missing"]
+        );
+
+        let invalid_number = extension(&OID_X509_EXT_CRL_NUMBER, true, &int(&[0x80; 21]));
+        let data = CrlBuilder::with_extensions(vec![invalid_number]).build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert_eq!(
+            crl_lint_failures(CRL_NUMBER_LINTS, &crl),
+            [
+                "rfc:crl_number_critical",
+                "rfc:crl_number_negative",
+                "rfc:crl_number_too_long",
+            ]
+        );
+
+        // delta CRLs
+        let delta = extension(&OID_X509_EXT_DELTA_CRL_INDICATOR, true, &int(&[0x0f]));
+        let data = CrlBuilder::with_extensions(vec![crl_number.clone(), delta]).build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert!(crl_lint_failures(CRL_NUMBER_LINTS, &crl).is_empty());
+
+        let delta = extension(&OID_X509_EXT_DELTA_CRL_INDICATOR, false, &int(&[0x10]));
+        let data = CrlBuilder::with_extensions(vec![crl_number, delta]).build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert_eq!(
+            crl_lint_failures(CRL_NUMBER_LINTS, &crl),
+            [
+                "rfc:crl_delta_indicator_not_critical",
+                "rfc:crl_delta_base_not_lower",
+            ]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use x509_parser::prelude::CertificateRevocationList;
+use x509_parser::time::ASN1Time;
+
+use super::issuing_distribution_point::issuing_distribution_point;
+use crate::*;
+
+pub(crate) const CRL_VALIDITY_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_NEXT_UPDATE_MISSING, crl_next_update_missing),
+    (
+        CRL_NEXT_UPDATE_BEFORE_THIS_UPDATE,
+        crl_next_update_before_this_update,
+    ),
+    (CRL_YEAR_PRE2049_UTC, crl_year_pre2049_utc),
+    (CRL_YEAR_POST2049_UTC, crl_year_post2049_utc),
+];
+
+pub(crate) const CRL_VALIDITY_CONFIGURABLE_LINTS: &[(LintDefinition, ConfigurableCRLLint)] =
+    &[(CRL_VALIDITY_TOO_LONG, crl_validity_too_long)];
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Return all dates of the CRL (thisUpdate, nextUpdate and revocation dates), with a label
+fn crl_dates(crl: &CertificateRevocationList) -> Vec<(String, ASN1Time)> {
+    let mut dates = vec![("thisUpdate".to_string(), crl.last_update())];
+    if let Some(next_update) = crl.next_update() {
+        dates.push(("nextUpdate".to_string(), next_update));
+    }
+    for revoked in crl.iter_revoked_certificates() {
+        let label = format!("revocationDate ({})", revoked.raw_serial_as_string());
+        dates.push((label, revoked.revocation_date));
+    }
+    dates
+}
+
+/// Return true if the CRL only contains CA certificates (IssuingDistributionPoint extension)
+///
+/// Returns `None` if the extension is absent: the scope of the CRL is then unknown.
+fn only_contains_ca_certs(crl: &CertificateRevocationList) -> Option<bool> {
+    issuing_distribution_point(crl).map(|idp| idp.only_contains_ca_certs)
+}
+
+lint_definition!(
+    CRL_NEXT_UPDATE_MISSING,
+    "rfc:crl_next_update_missing",
+    "Conforming CRL issuers MUST include the nextUpdate time in all CRLs",
+    "RFC5280: 5.1.2.5"
+);
+crl_lint!(
+    pub(super) crl_next_update_missing,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| crl.next_update().is_none()
+);
+
+lint_definition!(
+    CRL_NEXT_UPDATE_BEFORE_THIS_UPDATE,
+    "rfc:crl_next_update_before_this_update",
+    "CRL nextUpdate MUST be later than thisUpdate",
+    "RFC5280: 5.1.2.5"
+);
+crl_lint!(
+    pub(super) crl_next_update_before_this_update,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| crl
+        .next_update()
+        .is_some_and(|next_update| next_update.timestamp() <= crl.last_update().timestamp())
+);
+
+lint_definition!(
+    CRL_YEAR_PRE2049_UTC,
+    "rfc:crl_year_pre2049_utc",
+    "CRL dates through 2049 MUST be encoded as UTCTime",
+    "RFC5280: 5.1.2.4"
+);
+pub(super) fn crl_year_pre2049_utc(crl: &CertificateRevocationList) -> LintResult {
+    for (label, date) in crl_dates(crl) {
+        if date.to_datetime().year() <= 2049 && !date.is_utctime() {
+            return LintResult::new_details(LintStatus::Error, LintDetails::new(label));
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_YEAR_POST2049_UTC,
+    "rfc:crl_year_post2049_utc",
+    "CRL dates in 2050 or later MUST be encoded as GeneralizedTime",
+    "RFC5280: 5.1.2.4"
+);
+pub(super) fn crl_year_post2049_utc(crl: &CertificateRevocationList) -> LintResult {
+    for (label, date) in crl_dates(crl) {
+        if date.to_datetime().year() > 2049 && date.is_utctime() {
+            return LintResult::new_details(LintStatus::Error, LintDetails::new(label));
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_VALIDITY_TOO_LONG,
+    "rfc:crl_validity_too_long",
+    "Interval between CRL thisUpdate and nextUpdate is longer than the maximum",
+    "CABF BR: 4.9.7"
+);
+/// The maximum depends on the scope of the CRL: CRLs only containing CA certificates use
+/// `ca_crl_max_validity_days`. If the scope is unknown (no IssuingDistributionPoint), exceeding
+/// only `crl_max_validity_days` is a warning.
+pub(super) fn crl_validity_too_long(
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> LintResult {
+    let Some(next_update) = crl.next_update() else {
+        return LintResult::pass();
+    };
+    let seconds = next_update.timestamp() - crl.last_update().timestamp();
+    let exceeds = |max_days: u32| seconds > i64::from(max_days) * SECONDS_PER_DAY;
+    let ca_max_days = config.ca_crl_max_validity_days;
+    let (max_days, status) = match only_contains_ca_certs(crl) {
+        Some(true) => (ca_max_days, LintStatus::Error),
+        Some(false) => (config.crl_max_validity_days, LintStatus::Error),
+        // CRLs without IssuingDistributionPoint may be ARLs (for ex. the full CRL of a root CA),
+        // so only the maximum for CA certificates is an error
+        None if exceeds(ca_max_days) => (ca_max_days, LintStatus::Error),
+        None => (config.crl_max_validity_days, LintStatus::Warn),
+    };
+    if exceeds(max_days) {
+        let days = seconds as f64 / SECONDS_PER_DAY as f64;
+        let details = LintDetails::new(format!("{days:.1} days (maximum: {max_days})"));
+        return LintResult::new_details(status, details);
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::OID_X509_EXT_ISSUER_DISTRIBUTION_POINT;
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    /// Run crl_validity_too_long on a CRL issued on 2025-01-01
+    fn validity_status(next_update: &[u8], idp: Option<Vec<u8>>) -> LintStatus {
+        let extensions = idp
+            .map(|idp| extension(&OID_X509_EXT_ISSUER_DISTRIBUTION_POINT, true, &idp))
+            .into_iter()
+            .collect();
+        let data = CrlBuilder {
+            next_update: Some(der(0x17, next_update)),
+            extensions,
+            ..Default::default()
+        }
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        crl_validity_too_long(&crl, &LintConfig::default()).status
+    }
+
+    #[test]
+    fn crl_validity() {
+        let days_30 = b"250131000000Z";
+        let days_400 = b"260205000000Z";
+        let only_user_certs = seq(&[&der(0x81, &[0xff])]);
+        let only_ca_certs = seq(&[&der(0x82, &[0xff])]);
+
+        // no IssuingDistributionPoint: the CRL may be an ARL
+        assert_eq!(validity_status(b"250108000000Z", None), LintStatus::Pass);
+        assert_eq!(validity_status(days_30, None), LintStatus::Warn);
+        assert_eq!(validity_status(days_400, None), LintStatus::Error);
+
+        let idp = Some(only_ca_certs);
+        assert_eq!(validity_status(days_30, idp.clone()), LintStatus::Pass);
+        assert_eq!(validity_status(days_400, idp), LintStatus::Error);
+
+        let idp = Some(only_user_certs);
+        assert_eq!(
+            validity_status(b"250108000000Z", idp.clone()),
+            LintStatus::Pass
+        );
+        assert_eq!(validity_status(days_30, idp), LintStatus::Error);
+    }
+
+    fn failures(builder: CrlBuilder) -> Vec<String> {
+        let data = builder.build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        crl_lint_failures(CRL_VALIDITY_LINTS, &crl)
+    }
+
+    #[test]
+    fn next_update() {
+        assert!(failures(CrlBuilder::default()).is_empty());
+        let missing = CrlBuilder {
+            next_update: None,
+            ..Default::default()
+        };
+        assert_eq!(failures(missing), ["rfc:crl_next_update_missing"]);
+        let before = CrlBuilder {
+            next_update: Some(der(0x17, b"241231000000Z")),
+            ..Default::default()
+        };
+        assert_eq!(failures(before), ["rfc:crl_next_update_before_this_update"]);
+    }
+
+    #[test]
+    fn date_encoding() {
+        let this_update = CrlBuilder {
+            this_update: der(0x18, b"20250101000000Z"),
+            ..Default::default()
+        };
+        assert_eq!(failures(this_update), ["rfc:crl_year_pre2049_utc"]);
+        let revocation_date = CrlBuilder {
+            revoked: vec![der(
+                0x30,
+                &[int(&[1]), der(0x18, b"20241215000000Z")].concat(),
+            )],
+            ..Default::default()
+        };
+        assert_eq!(failures(revocation_date), ["rfc:crl_year_pre2049_utc"]);
+
+        let post_2049 = CrlBuilder {
+            next_update: Some(der(0x18, b"20500101000000Z")),
+            ..Default::default()
+        };
+        assert!(failures(post_2049).is_empty());
+        // UTCTime years 50 to 99 are read as 1950 to 1999, so the date is before thisUpdate
+        let utc_2050 = CrlBuilder {
+            next_update: Some(der(0x17, b"500101000000Z")),
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(utc_2050),
+            ["rfc:crl_next_update_before_this_update"]
+        );
+    }
+}
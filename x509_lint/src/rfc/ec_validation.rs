@@ -0,0 +1,105 @@
+//! Validation of EC public keys (requires feature `ec-validation`)
+//!
+//! The NIST curves have a cofactor of 1, so a point on the curve which is not the point at
+//! infinity is also in the subgroup of prime order.
+//!
+//! Only P-256, P-384 and P-521 are supported: points on other named curves are not validated,
+//! and are reported as warnings.
+
+use x509_parser::asn1_rs::Oid;
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::X509Certificate;
+
+use super::spki::ec_named_curve;
+use crate::*;
+
+pub(crate) const EC_VALIDATION_LINTS: &[(LintDefinition, CertificateLint)] =
+    &[(SPKI_EC_POINT_NOT_ON_CURVE, spki_ec_point_not_on_curve)];
+
+/// Decode and validate a SEC1 encoded point on the named curve
+///
+/// Returns `None` if the curve is not supported.
+fn validate_point(curve: &Oid, point: &[u8]) -> Option<bool> {
+    let valid = if *curve == OID_EC_P256 {
+        p256::PublicKey::from_sec1_bytes(point).is_ok()
+    } else if *curve == OID_NIST_EC_P384 {
+        p384::PublicKey::from_sec1_bytes(point).is_ok()
+    } else if *curve == OID_NIST_EC_P521 {
+        p521::PublicKey::from_sec1_bytes(point).is_ok()
+    } else {
+        return None;
+    };
+    Some(valid)
+}
+
+lint_definition!(
+    SPKI_EC_POINT_NOT_ON_CURVE,
+    "rfc:spki_ec_point_not_on_curve",
+    "EC public key MUST be a valid point on the named curve (not the point at infinity, coordinates in range)",
+    "CABF BR: 6.1.6"
+);
+pub(super) fn spki_ec_point_not_on_curve(x509: &X509Certificate) -> LintResult {
+    let Some(Some(curve)) = ec_named_curve(x509) else {
+        return LintResult::pass();
+    };
+    let point = x509.public_key().subject_public_key.data.as_ref();
+    if point.first() == Some(&0) {
+        return LintResult::new_details(LintStatus::Error, "point at infinity".into());
+    }
+    match validate_point(&curve, point) {
+        Some(true) => LintResult::pass(),
+        Some(false) => LintResult::new_details(LintStatus::Error, "invalid point".into()),
+        None => {
+            let details = format!(
+                "point not validated, unsupported curve {}",
+                curve.to_id_string()
+            );
+            LintResult::new_details(LintStatus::Warn, LintDetails::new(details))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::oids::OID_EC_SECP256K1;
+    use crate::rfc::testing::*;
+
+    fn ec_certificate(curve: &Oid, point: &[u8]) -> Vec<u8> {
+        let algorithm = seq(&[&oid(&OID_KEY_TYPE_EC_PUBLIC_KEY), &oid(curve)]);
+        CertificateBuilder {
+            spki: seq(&[&algorithm, &bits(point)]),
+            ..Default::default()
+        }
+        .build()
+    }
+
+    #[test]
+    fn ec_points() {
+        let data = CertificateBuilder::default().build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(spki_ec_point_not_on_curve(&x509).status, LintStatus::Pass);
+        let point = x509.public_key().subject_public_key.data.to_vec();
+
+        // change the last byte of the y coordinate
+        let mut off_curve = point.clone();
+        off_curve[64] ^= 1;
+        let data = ec_certificate(&OID_EC_P256, &off_curve);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        let result = spki_ec_point_not_on_curve(&x509);
+        assert_eq!(result.status, LintStatus::Error);
+        assert_eq!(result.details.unwrap().to_string(), "invalid point");
+
+        let data = ec_certificate(&OID_EC_P256, &[0]);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        let result = spki_ec_point_not_on_curve(&x509);
+        assert_eq!(result.status, LintStatus::Error);
+        assert_eq!(result.details.unwrap().to_string(), "point at infinity");
+
+        let data = ec_certificate(&OID_EC_SECP256K1, &point);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(spki_ec_point_not_on_curve(&x509).status, LintStatus::Warn);
+    }
+}
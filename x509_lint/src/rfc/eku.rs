@@ -0,0 +1,213 @@
+use x509_parser::asn1_rs::{FromDer, Oid};
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::OID_X509_EXT_EXTENDED_KEY_USAGE;
+use x509_parser::prelude::X509Certificate;
+
+use crate::*;
+
+pub(crate) const EKU_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_EKU_EMPTY, cert_ext_eku_empty),
+    (CERT_EXT_EKU_ANY_CRITICAL, cert_ext_eku_any_critical),
+    (CERT_EXT_EKU_DUPLICATE, cert_ext_eku_duplicate),
+    (CERT_EXT_EKU_KU_INCONSISTENT, cert_ext_eku_ku_inconsistent),
+    (CERT_EXT_EKU_ANY_CA, cert_ext_eku_any_ca),
+];
+
+/// Return the first ExtendedKeyUsage extension, and its criticality
+fn extended_key_usage<'a>(x509: &'a X509Certificate) -> Option<(bool, &'a ExtendedKeyUsage<'a>)> {
+    x509.extensions()
+        .iter()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::ExtendedKeyUsage(eku) => Some((ext.critical, eku)),
+            _ => None,
+        })
+}
+
+lint_definition!(
+    CERT_EXT_EKU_EMPTY,
+    "rfc:cert_ext_eku_empty",
+    "The ExtendedKeyUsage extension MUST contain at least one KeyPurposeId",
+    "RFC5280: 4.2.1.12"
+);
+certificate_lint!(
+    pub(super) cert_ext_eku_empty,
+    LintStatus::Error,
+    |x509: &X509Certificate| extended_key_usage(x509).is_some_and(|(_, eku)| {
+        !(eku.any
+            || eku.server_auth
+            || eku.client_auth
+            || eku.code_signing
+            || eku.email_protection
+            || eku.time_stamping
+            || eku.ocsp_signing)
+            && eku.other.is_empty()
+    })
+);
+
+lint_definition!(
+    CERT_EXT_EKU_ANY_CRITICAL,
+    "rfc:cert_ext_eku_any_critical",
+    "Conforming CAs SHOULD NOT mark ExtendedKeyUsage as critical if anyExtendedKeyUsage is present",
+    "RFC5280: 4.2.1.12"
+);
+certificate_lint!(
+    pub(super) cert_ext_eku_any_critical,
+    LintStatus::Warn,
+    |x509: &X509Certificate| {
+        extended_key_usage(x509).is_some_and(|(critical, eku)| critical && eku.any)
+    }
+);
+
+lint_definition!(
+    CERT_EXT_EKU_DUPLICATE,
+    "rfc:cert_ext_eku_duplicate",
+    "Duplicate KeyPurposeId in 'ExtendedKeyUsage'"
+);
+pub(super) fn cert_ext_eku_duplicate(x509: &X509Certificate) -> LintResult {
+    // duplicates are merged by the parser, read the raw value
+    for ext in x509.iter_extensions() {
+        if ext.oid != OID_X509_EXT_EXTENDED_KEY_USAGE {
+            continue;
+        }
+        if let Ok((_, purposes)) = <Vec<Oid>>::from_der(ext.value) {
+            for (idx, purpose) in purposes.iter().enumerate() {
+                if purposes[..idx].contains(purpose) {
+                    let details = LintDetails::new(purpose.to_id_string());
+                    return LintResult::new_details(LintStatus::Warn, details);
+                }
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_EKU_KU_INCONSISTENT,
+    "rfc:cert_ext_eku_ku_inconsistent",
+    "KeyUsage is not consistent with the purposes from ExtendedKeyUsage",
+    "RFC5280: 4.2.1.12"
+);
+pub(super) fn cert_ext_eku_ku_inconsistent(x509: &X509Certificate) -> LintResult {
+    let Some((_, eku)) = extended_key_usage(x509) else {
+        return LintResult::pass();
+    };
+    let Some(ku) = x509
+        .extensions()
+        .iter()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::KeyUsage(ku) => Some(ku),
+            _ => None,
+        })
+    else {
+        return LintResult::pass();
+    };
+    // purposes and compatible key usage bits, from the table in RFC5280 4.2.1.12
+    let checks = [
+        (
+            eku.server_auth,
+            "serverAuth",
+            ku.digital_signature() || ku.key_encipherment() || ku.key_agreement(),
+        ),
+        (
+            eku.client_auth,
+            "clientAuth",
+            ku.digital_signature() || ku.key_agreement(),
+        ),
+        (eku.code_signing, "codeSigning", ku.digital_signature()),
+        (
+            eku.email_protection,
+            "emailProtection",
+            ku.digital_signature()
+                || ku.non_repudiation()
+                || ku.key_encipherment()
+                || ku.key_agreement(),
+        ),
+        (
+            eku.time_stamping,
+            "timeStamping",
+            ku.digital_signature() || ku.non_repudiation(),
+        ),
+        (
+            eku.ocsp_signing,
+            "OCSPSigning",
+            ku.digital_signature() || ku.non_repudiation(),
+        ),
+    ];
+    for (present, purpose, consistent) in checks {
+        if present && !consistent {
+            let details = LintDetails::new(format!("{purpose} with KeyUsage {ku}"));
+            return LintResult::new_details(LintStatus::Warn, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_EKU_ANY_CA,
+    "rfc:cert_ext_eku_any_ca",
+    "CA certificate contains anyExtendedKeyUsage"
+);
+certificate_lint!(
+    pub(super) cert_ext_eku_any_ca,
+    LintStatus::Warn,
+    |x509: &X509Certificate| {
+        x509.is_ca() && extended_key_usage(x509).is_some_and(|(_, eku)| eku.any)
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::*;
+
+    use super::*;
+    use crate::rfc::oids::{OID_KP_ANY_EXTENDED_KEY_USAGE, OID_KP_CLIENT_AUTH, OID_KP_SERVER_AUTH};
+    use crate::rfc::testing::*;
+
+    fn eku(critical: bool, purposes: &[&Oid]) -> Vec<u8> {
+        let purposes: Vec<_> = purposes.iter().map(|purpose| oid(purpose)).collect();
+        let value = der(0x30, &purposes.concat());
+        extension(&OID_X509_EXT_EXTENDED_KEY_USAGE, critical, &value)
+    }
+
+    #[test]
+    fn eku_lints() {
+        // digitalSignature
+        let ku = extension(&OID_X509_EXT_KEY_USAGE, true, &der(0x03, &[7, 0x80]));
+        let server_client = [&OID_KP_SERVER_AUTH, &OID_KP_CLIENT_AUTH];
+        let data =
+            CertificateBuilder::with_extensions(vec![ku, eku(false, &server_client)]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(EKU_LINTS, &x509).is_empty());
+
+        // CA with critical anyExtendedKeyUsage, duplicate serverAuth, and keyCertSign only
+        let ku = extension(&OID_X509_EXT_KEY_USAGE, true, &der(0x03, &[2, 0x04]));
+        let purposes = [
+            &OID_KP_ANY_EXTENDED_KEY_USAGE,
+            &OID_KP_SERVER_AUTH,
+            &OID_KP_SERVER_AUTH,
+        ];
+        let data = CertificateBuilder::with_extensions(vec![
+            basic_constraints(true),
+            ku,
+            eku(true, &purposes),
+        ])
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(EKU_LINTS, &x509),
+            [
+                "rfc:cert_ext_eku_any_critical",
+                "rfc:cert_ext_eku_duplicate",
+                "rfc:cert_ext_eku_ku_inconsistent",
+                "rfc:cert_ext_eku_any_ca",
+            ]
+        );
+
+        let data = CertificateBuilder::with_extensions(vec![eku(false, &[])]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(EKU_LINTS, &x509),
+            ["rfc:cert_ext_eku_empty"]
+        );
+    }
+}
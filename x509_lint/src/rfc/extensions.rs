@@ -1,8 +1,14 @@
+use x509_parser::asn1_rs::Oid;
 use x509_parser::certificate::X509Certificate;
 use x509_parser::extensions::*;
+use x509_parser::oid_registry::{
+    OID_X509_EXT_DELTA_CRL_INDICATOR, OID_X509_EXT_FRESHEST_CRL, OID_X509_EXT_ISSUER,
+};
 use x509_parser::prelude::CertificateRevocationList;
 use x509_parser::x509::X509Version;
 
+use super::oids::OID_PKIX_SUBJECT_INFO_ACCESS;
+
 use crate::*;
 use crate::{certificate_lint, lint_definition};
 
@@ -10,11 +16,52 @@ pub(crate) const EXTENSION_LINTS: &[(LintDefinition, CertificateLint)] = &[
     (CERT_EXTENSION_NOTV3, cert_extensions_notv3),
     (CERT_EXTENSION_NOTSUPPORTED, cert_extensions_unsuppported),
     (CERT_EXTENSION_PARSEERROR, cert_extensions_parse_error),
+    (CERT_EXTENSION_DUPLICATE, cert_extensions_duplicate),
     (CERT_EXT_SAN_INVALID_CHARSET, cert_ext_san_invalid_charset),
 ];
 
-pub(crate) const CRL_EXTENSION_LINTS: &[(LintDefinition, CRLLint)] =
-    &[(CRL_EXTENSION_NOTV2, crl_extensions_notv2)];
+pub(crate) const EXTENSION_CONFIGURABLE_LINTS: &[(LintDefinition, ConfigurableCertificateLint)] =
+    &[(
+        CERT_EXTENSION_UNSUPPORTED_CRITICAL,
+        cert_extensions_unsupported_critical,
+    )];
+
+pub(crate) const CRL_EXTENSION_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_EXTENSION_NOTV2, crl_extensions_notv2),
+    (CRL_EXTENSION_DUPLICATE, crl_extensions_duplicate),
+];
+
+pub(crate) const CRL_EXTENSION_CONFIGURABLE_LINTS: &[(LintDefinition, ConfigurableCRLLint)] = &[(
+    CRL_EXTENSION_UNSUPPORTED_CRITICAL,
+    crl_extensions_unsupported_critical,
+)];
+
+/// Extensions not parsed by `x509_parser`, but checked by lints from this crate
+const RECOGNIZED_EXTENSIONS: &[Oid<'static>] = &[
+    OID_X509_EXT_FRESHEST_CRL,
+    OID_PKIX_SUBJECT_INFO_ACCESS,
+    OID_X509_EXT_DELTA_CRL_INDICATOR,
+    OID_X509_EXT_ISSUER,
+];
+
+/// Return true if the extension is neither supported by the parser, nor recognized by lints
+/// or allowed by configuration
+fn is_unrecognized(ext: &X509Extension, config: &LintConfig) -> bool {
+    matches!(
+        ext.parsed_extension(),
+        ParsedExtension::UnsupportedExtension { .. }
+    ) && !RECOGNIZED_EXTENSIONS.contains(&ext.oid)
+        && !config.allowed_critical_extensions.contains(&ext.oid)
+}
+
+/// Return the OID of the first extension present more than once
+fn find_duplicate(extensions: &[X509Extension]) -> Option<String> {
+    extensions
+        .iter()
+        .enumerate()
+        .find(|(idx, ext)| extensions[..*idx].iter().any(|e| e.oid == ext.oid))
+        .map(|(_, ext)| ext.oid.to_id_string())
+}
 
 lint_definition!(
     CERT_EXTENSION_NOTV3,
@@ -58,11 +105,44 @@ pub(super) fn cert_extensions_parse_error(x509: &X509Certificate) -> LintResult
     LintResult::pass()
 }
 
+lint_definition!(
+    CERT_EXTENSION_DUPLICATE,
+    "rfc:cert_extensions_duplicate",
+    "A certificate MUST NOT include more than one instance of a particular extension",
+    "RFC5280: 4.2"
+);
+pub(super) fn cert_extensions_duplicate(x509: &X509Certificate) -> LintResult {
+    match find_duplicate(x509.extensions()) {
+        Some(oid) => LintResult::new_details(LintStatus::Error, LintDetails::new(oid)),
+        None => LintResult::pass(),
+    }
+}
+
+lint_definition!(
+    CERT_EXTENSION_UNSUPPORTED_CRITICAL,
+    "rfc:cert_extensions_unsupported_critical",
+    "Unrecognized critical extension, certificate MUST be rejected",
+    "RFC5280: 4.2"
+);
+pub(super) fn cert_extensions_unsupported_critical(
+    x509: &X509Certificate,
+    config: &LintConfig,
+) -> LintResult {
+    for ext in x509.extensions() {
+        if ext.critical && is_unrecognized(ext, config) {
+            let details = LintDetails::new(ext.oid.to_id_string());
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
 lint_definition!(
     CERT_EXT_SAN_INVALID_CHARSET,
     "rfc:cert_ext_san_invalid_charset",
     "Invalid charset in 'SubjectAltName' entry"
 );
+#[allow(clippy::collapsible_match)]
 pub(super) fn cert_ext_san_invalid_charset(x509: &X509Certificate) -> LintResult {
     for ext in x509.extensions() {
         if let ParsedExtension::SubjectAlternativeName(san) = ext.parsed_extension() {
@@ -95,3 +175,101 @@ crl_lint!(
     LintStatus::Warn,
     |crl: &CertificateRevocationList| !crl.extensions().is_empty() && crl.version() != Some(X509Version::V2)
 );
+
+lint_definition!(
+    CRL_EXTENSION_DUPLICATE,
+    "rfc:crl_extensions_duplicate",
+    "A CRL MUST NOT include more than one instance of a particular extension",
+    "RFC5280: 5.2"
+);
+pub(super) fn crl_extensions_duplicate(crl: &CertificateRevocationList) -> LintResult {
+    match find_duplicate(crl.extensions()) {
+        Some(oid) => LintResult::new_details(LintStatus::Error, LintDetails::new(oid)),
+        None => LintResult::pass(),
+    }
+}
+
+lint_definition!(
+    CRL_EXTENSION_UNSUPPORTED_CRITICAL,
+    "rfc:crl_extensions_unsupported_critical",
+    "Unrecognized critical extension, CRL validation MUST fail",
+    "RFC5280: 5.2"
+);
+pub(super) fn crl_extensions_unsupported_critical(
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> LintResult {
+    for ext in crl.extensions() {
+        if ext.critical && is_unrecognized(ext, config) {
+            let details = LintDetails::new(ext.oid.to_id_string());
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::{
+        OID_X509_EXT_CRL_NUMBER, OID_X509_EXT_ISSUER_DISTRIBUTION_POINT,
+    };
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    #[test]
+    fn unsupported_critical_extension() {
+        let private_oid: Oid<'static> = "1.3.6.1.4.1.311.21.10".parse().unwrap();
+        let private_ext = extension(&private_oid, true, &seq(&[]));
+        let data = CertificateBuilder::with_extensions(vec![private_ext]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+
+        let mut config = LintConfig::default();
+        let result = cert_extensions_unsupported_critical(&x509, &config);
+        assert_eq!(result.status, LintStatus::Error);
+        config.allowed_critical_extensions.push(private_oid);
+        let result = cert_extensions_unsupported_critical(&x509, &config);
+        assert_eq!(result.status, LintStatus::Pass);
+        // the extension is still reported as not supported by the linter
+        let result = cert_extensions_unsuppported(&x509);
+        assert_eq!(result.status, LintStatus::Warn);
+    }
+
+    #[test]
+    fn duplicate_extensions() {
+        let extensions = vec![basic_constraints(false), basic_constraints(false)];
+        let data = CertificateBuilder::with_extensions(extensions.clone()).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        let result = cert_extensions_duplicate(&x509);
+        assert_eq!(result.status, LintStatus::Error);
+        assert_eq!(result.details.unwrap().to_string(), "2.5.29.19");
+
+        let crl_number = extension(&OID_X509_EXT_CRL_NUMBER, false, &int(&[1]));
+        let data = CrlBuilder::with_extensions(vec![crl_number.clone(), crl_number]).build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        let result = crl_extensions_duplicate(&crl);
+        assert_eq!(result.status, LintStatus::Error);
+        assert_eq!(result.details.unwrap().to_string(), "2.5.29.20");
+    }
+
+    #[test]
+    fn crl_unsupported_critical_extension() {
+        let private_oid: Oid<'static> = "1.3.6.1.4.1.311.21.10".parse().unwrap();
+        let data = CrlBuilder::with_extensions(vec![
+            extension(&private_oid, true, &seq(&[])),
+            // recognized by the IssuingDistributionPoint lints
+            extension(&OID_X509_EXT_ISSUER_DISTRIBUTION_POINT, true, &seq(&[])),
+        ])
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+
+        let mut config = LintConfig::default();
+        let result = crl_extensions_unsupported_critical(&crl, &config);
+        assert_eq!(result.status, LintStatus::Error);
+        assert_eq!(result.details.unwrap().to_string(), "1.3.6.1.4.1.311.21.10");
+        config.allowed_critical_extensions.push(private_oid);
+        let result = crl_extensions_unsupported_critical(&crl, &config);
+        assert_eq!(result.status, LintStatus::Pass);
+    }
+}
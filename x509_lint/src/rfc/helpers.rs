@@ -0,0 +1,218 @@
+use x509_parser::asn1_rs::{Any, FromDer, Sequence, ToDer};
+use x509_parser::prelude::X509Certificate;
+use x509_parser::x509::AlgorithmIdentifier;
+
+/// Return true if the certificate is self-issued (subject and issuer are identical)
+///
+/// RFC5280 allows some checks to be relaxed for self-signed certificates. Signature is not
+/// verified, so this is only an approximation.
+pub(super) fn is_self_issued(x509: &X509Certificate) -> bool {
+    x509.subject().as_raw() == x509.issuer().as_raw()
+}
+
+/// Iterate on the DER objects contained in `data` (for ex. the content of a SEQUENCE)
+///
+/// Iteration stops at the first parsing error.
+pub(super) fn der_items(mut data: &[u8]) -> impl Iterator<Item = Any<'_>> {
+    std::iter::from_fn(move || {
+        if data.is_empty() {
+            return None;
+        }
+        let (rem, any) = Any::from_der(data).ok()?;
+        data = rem;
+        Some(any)
+    })
+}
+
+/// Return the DER encoding of an AlgorithmIdentifier
+///
+/// The parser does not keep the raw encoding of the signatureAlgorithm field of certificates and
+/// CRLs, so it is built from the OID and the raw tag and content of the parameters. Returns
+/// `None` if the encoding fails.
+pub(super) fn algorithm_identifier_der(alg: &AlgorithmIdentifier) -> Option<Vec<u8>> {
+    let mut content = alg.algorithm.to_der_vec().ok()?;
+    if let Some(parameters) = &alg.parameters {
+        content.extend(parameters.to_der_vec().ok()?);
+    }
+    Sequence::new(content.into()).to_der_vec().ok()
+}
+
+/// Return the big-endian integer without leading zero bytes
+pub(super) fn strip_leading_zeroes(b: &[u8]) -> &[u8] {
+    let start = b.iter().position(|&x| x != 0).unwrap_or(b.len());
+    &b[start..]
+}
+
+/// Number of significant bits of a positive big-endian integer
+pub(super) fn bit_length(b: &[u8]) -> usize {
+    match strip_leading_zeroes(b) {
+        [] => 0,
+        b => b.len() * 8 - b[0].leading_zeros() as usize,
+    }
+}
+
+/// Check that `name` is a DNS name in the "preferred name syntax"
+///
+/// See RFC1034 section 3.5, as modified by RFC1123 section 2.1. A leftmost wildcard
+/// label (`*`) is accepted, if followed by at least one label.
+pub(super) fn check_dns_name(name: &str) -> Result<(), &'static str> {
+    if name == " " {
+        return Err("DNS name ' ' MUST NOT be used");
+    }
+    if name.is_empty() {
+        return Err("empty DNS name");
+    }
+    if name.ends_with('.') {
+        return Err("trailing dot");
+    }
+    if name.len() > 253 {
+        return Err("DNS name too long");
+    }
+    if name == "*" {
+        return Err("wildcard without domain");
+    }
+    for (idx, label) in name.split('.').enumerate() {
+        if idx == 0 && label == "*" {
+            continue;
+        }
+        check_dns_label(label)?;
+    }
+    Ok(())
+}
+
+fn check_dns_label(label: &str) -> Result<(), &'static str> {
+    if label.is_empty() {
+        return Err("empty label");
+    }
+    if label.len() > 63 {
+        return Err("label too long");
+    }
+    if !label
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    {
+        return Err("invalid character in label");
+    }
+    if label.starts_with('-') || label.ends_with('-') {
+        return Err("label starts or ends with hyphen");
+    }
+    Ok(())
+}
+
+/// Check that `uri` is an absolute URI with an authority containing a host, and return the
+/// scheme and host
+///
+/// This is a simplified version of the RFC3986 syntax, sufficient for URIs found in certificates.
+pub(super) fn check_uri(uri: &str) -> Result<(&str, &str), &'static str> {
+    if !uri.bytes().all(is_uri_char) {
+        return Err("invalid character in URI");
+    }
+    let (scheme, rest) = uri.split_once(':').ok_or("URI has no scheme")?;
+    let mut scheme_chars = scheme.bytes();
+    if !scheme_chars.next().is_some_and(|b| b.is_ascii_alphabetic())
+        || !scheme_chars.all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b))
+    {
+        return Err("invalid URI scheme");
+    }
+    let authority = rest.strip_prefix("//").ok_or("URI has no authority")?;
+    let authority = authority.split(['/', '?', '#']).next().unwrap_or_default();
+    // strip userinfo and port
+    let host = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    let host = if host.starts_with('[') {
+        // IP-literal
+        host.split_inclusive(']').next().unwrap_or_default()
+    } else {
+        host.split(':').next().unwrap_or_default()
+    };
+    if host.is_empty() {
+        return Err("URI has no host");
+    }
+    Ok((scheme, host))
+}
+
+fn is_uri_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"-._~:/?#[]@!$&'()*+,;=%".contains(&b)
+}
+
+/// Check that `name` is a valid `Mailbox` (addr-spec), as required for rfc822Name
+///
+/// See RFC5280 section 4.2.1.6, and RFC5321 section 4.1.2
+pub(super) fn check_rfc822_name(name: &str) -> Result<(), &'static str> {
+    let (local, domain) = name.rsplit_once('@').ok_or("missing '@'")?;
+    if local.is_empty() {
+        return Err("empty local-part");
+    }
+    if local.starts_with('"') {
+        if local.len() < 2 || !local.ends_with('"') {
+            return Err("invalid quoted local-part");
+        }
+    } else {
+        if local.contains('@') {
+            return Err("multiple '@'");
+        }
+        if local.split('.').any(|atom| {
+            atom.is_empty()
+                || !atom
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&b))
+        }) {
+            return Err("invalid local-part");
+        }
+    }
+    check_dns_name(domain).map_err(|_| "invalid domain")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(strip_leading_zeroes(&[0, 0, 1, 0]), [1, 0]);
+        assert_eq!(strip_leading_zeroes(&[0, 0]), []);
+        assert_eq!(bit_length(&[0x00, 0xff]), 8);
+        assert_eq!(bit_length(&[0x01, 0x00]), 9);
+        assert_eq!(bit_length(&[]), 0);
+    }
+
+    #[test]
+    fn dns_names() {
+        assert!(check_dns_name("www.example.com").is_ok());
+        assert!(check_dns_name("*.example.com").is_ok());
+        assert!(check_dns_name("xn--bcher-kva.example").is_ok());
+        assert!(check_dns_name(" ").is_err());
+        assert!(check_dns_name("*").is_err());
+        assert!(check_dns_name("www..example.com").is_err());
+        assert!(check_dns_name("www.example.com.").is_err());
+        assert!(check_dns_name("www.*.example.com").is_err());
+        assert!(check_dns_name("-www.example.com").is_err());
+        assert!(check_dns_name("under_score.example.com").is_err());
+    }
+
+    #[test]
+    fn uris() {
+        assert_eq!(
+            check_uri("http://crl.example.com/ca.crl"),
+            Ok(("http", "crl.example.com"))
+        );
+        assert_eq!(
+            check_uri("ldap://user@[2001:db8::1]:389/cn=CA"),
+            Ok(("ldap", "[2001:db8::1]"))
+        );
+        assert!(check_uri("crl.example.com/ca.crl").is_err());
+        assert!(check_uri("urn:oid:1.2.3").is_err());
+        assert!(check_uri("http:///ca.crl").is_err());
+        assert!(check_uri("http://example.com/a b").is_err());
+    }
+
+    #[test]
+    fn rfc822_names() {
+        assert!(check_rfc822_name("user@example.com").is_ok());
+        assert!(check_rfc822_name("first.last+tag@example.com").is_ok());
+        assert!(check_rfc822_name("\"a b\"@example.com").is_ok());
+        assert!(check_rfc822_name("example.com").is_err());
+        assert!(check_rfc822_name("@example.com").is_err());
+        assert!(check_rfc822_name("a@@example.com").is_err());
+        assert!(check_rfc822_name("a..b@example.com").is_err());
+    }
+}
@@ -0,0 +1,311 @@
+use x509_parser::asn1_rs::Oid;
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{FromDer, X509Certificate};
+
+use super::helpers::check_uri;
+use super::oids::OID_PKIX_SUBJECT_INFO_ACCESS;
+use crate::*;
+
+pub(crate) const INFO_ACCESS_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_AIA_CRITICAL, cert_ext_aia_critical),
+    (CERT_EXT_AIA_EMPTY, cert_ext_aia_empty),
+    (CERT_EXT_AIA_UNKNOWN_METHOD, cert_ext_aia_unknown_method),
+    (CERT_EXT_AIA_URI_INVALID, cert_ext_aia_uri_invalid),
+    (
+        CERT_EXT_AIA_URI_UNSUPPORTED_SCHEME,
+        cert_ext_aia_uri_unsupported_scheme,
+    ),
+    (CERT_EXT_SIA_CRITICAL, cert_ext_sia_critical),
+    (CERT_EXT_SIA_EMPTY, cert_ext_sia_empty),
+    (CERT_EXT_SIA_UNKNOWN_METHOD, cert_ext_sia_unknown_method),
+    (CERT_EXT_SIA_URI_INVALID, cert_ext_sia_uri_invalid),
+    (CERT_EXT_SIA_OCSP, cert_ext_sia_ocsp),
+];
+
+/// Access methods defined for AuthorityInfoAccess
+const AIA_METHODS: &[Oid<'static>] = &[
+    OID_PKIX_ACCESS_DESCRIPTOR_OCSP,
+    OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS,
+];
+
+/// Access methods defined for SubjectInfoAccess (RFC5280 and RFC6487)
+const SIA_METHODS: &[Oid<'static>] = &[
+    OID_PKIX_ACCESS_DESCRIPTOR_CA_REPOSITORY,
+    OID_PKIX_ACCESS_DESCRIPTOR_TIMESTAMPING,
+    OID_PKIX_ACCESS_DESCRIPTOR_RPKI_MANIFEST,
+    OID_PKIX_ACCESS_DESCRIPTOR_SIGNED_OBJECT,
+    OID_PKIX_ACCESS_DESCRIPTOR_RPKI_NOTIFY,
+];
+
+/// Return the access descriptions of the first extension with the given OID, and its criticality
+///
+/// SubjectInfoAccess has the same syntax as AuthorityInfoAccess, but is not parsed by
+/// `x509_parser`, so the raw value is always used.
+fn access_descriptions<'a>(
+    x509: &'a X509Certificate,
+    oid: &Oid,
+) -> Option<(bool, Vec<AccessDescription<'a>>)> {
+    let ext = x509.iter_extensions().find(|ext| ext.oid == *oid)?;
+    let (_, info) = AuthorityInfoAccess::from_der(ext.value).ok()?;
+    Some((ext.critical, info.accessdescs))
+}
+
+fn check_unknown_method(x509: &X509Certificate, oid: &Oid, known: &[Oid]) -> LintResult {
+    let Some((_, descs)) = access_descriptions(x509, oid) else {
+        return LintResult::pass();
+    };
+    match descs
+        .iter()
+        .find(|desc| !known.contains(&desc.access_method))
+    {
+        Some(desc) => {
+            let details = LintDetails::new(desc.access_method.to_id_string());
+            LintResult::new_details(LintStatus::Warn, details)
+        }
+        None => LintResult::pass(),
+    }
+}
+
+fn check_location_uris(x509: &X509Certificate, oid: &Oid) -> LintResult {
+    let Some((_, descs)) = access_descriptions(x509, oid) else {
+        return LintResult::pass();
+    };
+    for desc in &descs {
+        let GeneralName::URI(uri) = desc.access_location else {
+            continue;
+        };
+        // for LDAP, the host is only recommended
+        if uri.starts_with("ldap:///") {
+            continue;
+        }
+        if let Err(e) = check_uri(uri) {
+            let details = LintDetails::new(format!("'{uri}': {e}"));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_AIA_CRITICAL,
+    "rfc:cert_ext_aia_critical",
+    "Conforming CAs MUST mark the AuthorityInfoAccess extension as non-critical",
+    "RFC5280: 4.2.2.1"
+);
+certificate_lint!(
+    pub(super) cert_ext_aia_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_PKIX_AUTHORITY_INFO_ACCESS && ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_AIA_EMPTY,
+    "rfc:cert_ext_aia_empty",
+    "The AuthorityInfoAccess extension MUST contain at least one AccessDescription",
+    "RFC5280: 4.2.2.1"
+);
+certificate_lint!(
+    pub(super) cert_ext_aia_empty,
+    LintStatus::Error,
+    |x509: &X509Certificate| access_descriptions(x509, &OID_PKIX_AUTHORITY_INFO_ACCESS)
+        .is_some_and(|(_, descs)| descs.is_empty())
+);
+
+lint_definition!(
+    CERT_EXT_AIA_UNKNOWN_METHOD,
+    "rfc:cert_ext_aia_unknown_method",
+    "Unknown access method in 'AuthorityInfoAccess'",
+    "RFC5280: 4.2.2.1"
+);
+pub(super) fn cert_ext_aia_unknown_method(x509: &X509Certificate) -> LintResult {
+    check_unknown_method(x509, &OID_PKIX_AUTHORITY_INFO_ACCESS, AIA_METHODS)
+}
+
+lint_definition!(
+    CERT_EXT_AIA_URI_INVALID,
+    "rfc:cert_ext_aia_uri_invalid",
+    "Invalid URI in AuthorityInfoAccess accessLocation",
+    "RFC5280: 4.2.2.1"
+);
+pub(super) fn cert_ext_aia_uri_invalid(x509: &X509Certificate) -> LintResult {
+    check_location_uris(x509, &OID_PKIX_AUTHORITY_INFO_ACCESS)
+}
+
+lint_definition!(
+    CERT_EXT_AIA_URI_UNSUPPORTED_SCHEME,
+    "rfc:cert_ext_aia_uri_unsupported_scheme",
+    "caIssuers and OCSP accessLocation URIs should use the HTTP or LDAP scheme",
+    "RFC5280: 4.2.2.1"
+);
+pub(super) fn cert_ext_aia_uri_unsupported_scheme(x509: &X509Certificate) -> LintResult {
+    let Some((_, descs)) = access_descriptions(x509, &OID_PKIX_AUTHORITY_INFO_ACCESS) else {
+        return LintResult::pass();
+    };
+    for desc in &descs {
+        let schemes: &[&str] = if desc.access_method == OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS {
+            &["http", "https", "ldap"]
+        } else if desc.access_method == OID_PKIX_ACCESS_DESCRIPTOR_OCSP {
+            &["http", "https"]
+        } else {
+            continue;
+        };
+        let GeneralName::URI(uri) = desc.access_location else {
+            continue;
+        };
+        let scheme = uri.split_once(':').map_or("", |(scheme, _)| scheme);
+        if !schemes.contains(&scheme.to_ascii_lowercase().as_str()) {
+            let details = LintDetails::new(format!("'{uri}'"));
+            return LintResult::new_details(LintStatus::Warn, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SIA_CRITICAL,
+    "rfc:cert_ext_sia_critical",
+    "Conforming CAs MUST mark the SubjectInfoAccess extension as non-critical",
+    "RFC5280: 4.2.2.2"
+);
+certificate_lint!(
+    pub(super) cert_ext_sia_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_PKIX_SUBJECT_INFO_ACCESS && ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_SIA_EMPTY,
+    "rfc:cert_ext_sia_empty",
+    "The SubjectInfoAccess extension MUST contain at least one AccessDescription",
+    "RFC5280: 4.2.2.2"
+);
+certificate_lint!(
+    pub(super) cert_ext_sia_empty,
+    LintStatus::Error,
+    |x509: &X509Certificate| access_descriptions(x509, &OID_PKIX_SUBJECT_INFO_ACCESS)
+        .is_some_and(|(_, descs)| descs.is_empty())
+);
+
+lint_definition!(
+    CERT_EXT_SIA_UNKNOWN_METHOD,
+    "rfc:cert_ext_sia_unknown_method",
+    "Unknown access method in 'SubjectInfoAccess'",
+    "RFC5280: 4.2.2.2"
+);
+pub(super) fn cert_ext_sia_unknown_method(x509: &X509Certificate) -> LintResult {
+    // id-ad-ocsp is reported by cert_ext_sia_ocsp
+    let known = [SIA_METHODS, &[OID_PKIX_ACCESS_DESCRIPTOR_OCSP]].concat();
+    check_unknown_method(x509, &OID_PKIX_SUBJECT_INFO_ACCESS, &known)
+}
+
+lint_definition!(
+    CERT_EXT_SIA_URI_INVALID,
+    "rfc:cert_ext_sia_uri_invalid",
+    "Invalid URI in SubjectInfoAccess accessLocation",
+    "RFC5280: 4.2.2.2"
+);
+pub(super) fn cert_ext_sia_uri_invalid(x509: &X509Certificate) -> LintResult {
+    check_location_uris(x509, &OID_PKIX_SUBJECT_INFO_ACCESS)
+}
+
+lint_definition!(
+    CERT_EXT_SIA_OCSP,
+    "rfc:cert_ext_sia_ocsp",
+    "id-ad-ocsp is not a defined access method for 'SubjectInfoAccess'",
+    "RFC5280: 4.2.2.2"
+);
+certificate_lint!(
+    pub(super) cert_ext_sia_ocsp,
+    LintStatus::Warn,
+    |x509: &X509Certificate| access_descriptions(x509, &OID_PKIX_SUBJECT_INFO_ACCESS)
+        .is_some_and(|(_, descs)| descs
+            .iter()
+            .any(|desc| desc.access_method == OID_PKIX_ACCESS_DESCRIPTOR_OCSP))
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn info_access(ext_oid: &Oid, critical: bool, descs: &[(&Oid, &str)]) -> Vec<u8> {
+        let descs: Vec<_> = descs
+            .iter()
+            .map(|(method, uri)| seq(&[&oid(method), &der(0x86, uri.as_bytes())]))
+            .collect();
+        extension(ext_oid, critical, &der(0x30, &descs.concat()))
+    }
+
+    #[test]
+    fn info_access_lints() {
+        let aia = info_access(
+            &OID_PKIX_AUTHORITY_INFO_ACCESS,
+            false,
+            &[
+                (&OID_PKIX_ACCESS_DESCRIPTOR_OCSP, "http://ocsp.example.com"),
+                (
+                    &OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS,
+                    "http://ca.example.com/ca.crt",
+                ),
+            ],
+        );
+        let sia = info_access(
+            &OID_PKIX_SUBJECT_INFO_ACCESS,
+            false,
+            &[(
+                &OID_PKIX_ACCESS_DESCRIPTOR_CA_REPOSITORY,
+                "rsync://repo.example.com/repo/",
+            )],
+        );
+        let data = CertificateBuilder::with_extensions(vec![aia, sia]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(INFO_ACCESS_LINTS, &x509).is_empty());
+
+        let aia = info_access(
+            &OID_PKIX_AUTHORITY_INFO_ACCESS,
+            true,
+            &[
+                (&OID_PKIX_ACCESS_DESCRIPTOR_OCSP, "ldap://ocsp.example.com"),
+                (&OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS, "http:///ca.crt"),
+                (&OID_PKIX_ACCESS_DESCRIPTOR_DVCS, "http://dvcs.example.com"),
+            ],
+        );
+        let sia = info_access(
+            &OID_PKIX_SUBJECT_INFO_ACCESS,
+            true,
+            &[
+                (&OID_PKIX_ACCESS_DESCRIPTOR_OCSP, "http://ocsp.example.com"),
+                (&OID_PKIX_ACCESS_DESCRIPTOR_CA_ISSUERS, "ca.example.com"),
+            ],
+        );
+        let data = CertificateBuilder::with_extensions(vec![aia, sia]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(INFO_ACCESS_LINTS, &x509),
+            [
+                "rfc:cert_ext_aia_critical",
+                "rfc:cert_ext_aia_unknown_method",
+                "rfc:cert_ext_aia_uri_invalid",
+                "rfc:cert_ext_aia_uri_unsupported_scheme",
+                "rfc:cert_ext_sia_critical",
+                "rfc:cert_ext_sia_unknown_method",
+                "rfc:cert_ext_sia_uri_invalid",
+                "rfc:cert_ext_sia_ocsp",
+            ]
+        );
+
+        let aia = info_access(&OID_PKIX_AUTHORITY_INFO_ACCESS, false, &[]);
+        let sia = info_access(&OID_PKIX_SUBJECT_INFO_ACCESS, false, &[]);
+        let data = CertificateBuilder::with_extensions(vec![aia, sia]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(INFO_ACCESS_LINTS, &x509),
+            ["rfc:cert_ext_aia_empty", "rfc:cert_ext_sia_empty"]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use x509_parser::asn1_rs::{Any, FromDer};
+use x509_parser::extensions::{IssuingDistributionPoint, ParsedExtension, X509Extension};
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::CertificateRevocationList;
+
+use crate::*;
+
+pub(crate) const CRL_IDP_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_IDP_NOT_CRITICAL, crl_idp_not_critical),
+    (CRL_IDP_EMPTY, crl_idp_empty),
+    (
+        CRL_IDP_ONLY_CONTAINS_EXCLUSIVE,
+        crl_idp_only_contains_exclusive,
+    ),
+    (
+        CRL_IDP_INDIRECT_NO_CERTIFICATE_ISSUER,
+        crl_idp_indirect_no_certificate_issuer,
+    ),
+];
+
+/// Return the issuingDistributionPoint extension
+fn idp_extension<'a>(crl: &'a CertificateRevocationList) -> Option<&'a X509Extension<'a>> {
+    crl.extensions()
+        .iter()
+        .find(|ext| ext.oid == OID_X509_EXT_ISSUER_DISTRIBUTION_POINT)
+}
+
+/// Return the parsed issuingDistributionPoint extension, if present and valid
+pub(super) fn issuing_distribution_point<'a>(
+    crl: &'a CertificateRevocationList,
+) -> Option<&'a IssuingDistributionPoint<'a>> {
+    match idp_extension(crl)?.parsed_extension() {
+        ParsedExtension::IssuingDistributionPoint(idp) => Some(idp),
+        _ => None,
+    }
+}
+
+lint_definition!(
+    CRL_IDP_NOT_CRITICAL,
+    "rfc:crl_idp_not_critical",
+    "The issuingDistributionPoint extension MUST be marked critical",
+    "RFC5280: 5.2.5"
+);
+crl_lint!(
+    pub(super) crl_idp_not_critical,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| idp_extension(crl).is_some_and(|ext| !ext.critical)
+);
+
+lint_definition!(
+    CRL_IDP_EMPTY,
+    "rfc:crl_idp_empty",
+    "Conforming CRL issuers MUST NOT issue CRLs where the issuingDistributionPoint is an empty sequence",
+    "RFC5280: 5.2.5"
+);
+crl_lint!(
+    pub(super) crl_idp_empty,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| idp_extension(crl).is_some_and(|ext| {
+        Any::from_der(ext.value).is_ok_and(|(_, value)| value.data.is_empty())
+    })
+);
+
+lint_definition!(
+    CRL_IDP_ONLY_CONTAINS_EXCLUSIVE,
+    "rfc:crl_idp_only_contains_exclusive",
+    "At most one of onlyContainsUserCerts, onlyContainsCACerts and onlyContainsAttributeCerts may be set to TRUE",
+    "RFC5280: 5.2.5"
+);
+pub(super) fn crl_idp_only_contains_exclusive(crl: &CertificateRevocationList) -> LintResult {
+    let Some(idp) = issuing_distribution_point(crl) else {
+        return LintResult::pass();
+    };
+    let set: Vec<_> = [
+        ("onlyContainsUserCerts", idp.only_contains_user_certs),
+        ("onlyContainsCACerts", idp.only_contains_ca_certs),
+        (
+            "onlyContainsAttributeCerts",
+            idp.only_contains_attribute_certs,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, value)| value.then_some(name))
+    .collect();
+    if set.len() > 1 {
+        return LintResult::new_details(LintStatus::Error, LintDetails::new(set.join(", ")));
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_IDP_INDIRECT_NO_CERTIFICATE_ISSUER,
+    "rfc:crl_idp_indirect_no_certificate_issuer",
+    "Entries of an indirect CRL SHOULD carry the certificateIssuer extension",
+    "RFC5280: 5.3.3"
+);
+pub(super) fn crl_idp_indirect_no_certificate_issuer(
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    let Some(idp) = issuing_distribution_point(crl) else {
+        return LintResult::pass();
+    };
+    // the certificateIssuer of an entry also applies to all following entries, so only
+    // report indirect CRLs where no entry names its issuer
+    let mut revoked = crl.iter_revoked_certificates().peekable();
+    if idp.indirect_crl
+        && revoked.peek().is_some()
+        && !revoked.any(|revoked| revoked.find_extension(&OID_X509_EXT_ISSUER).is_some())
+    {
+        return LintResult::new(LintStatus::Warn);
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn idp_failures(critical: bool, fields: &[&[u8]], revoked: Vec<Vec<u8>>) -> Vec<String> {
+        let idp = extension(
+            &OID_X509_EXT_ISSUER_DISTRIBUTION_POINT,
+            critical,
+            &seq(fields),
+        );
+        let data = CrlBuilder {
+            revoked,
+            extensions: vec![idp],
+            ..Default::default()
+        }
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        crl_lint_failures(CRL_IDP_LINTS, &crl)
+    }
+
+    #[test]
+    fn idp_lints() {
+        let uri = der(0x86, b"http://crl.example.com/ca.crl");
+        let distribution_point = der(0xa0, &der(0xa0, &uri));
+        let only_user_certs = der(0x81, &[0xff]);
+        let only_ca_certs = der(0x82, &[0xff]);
+        let indirect_crl = der(0x84, &[0xff]);
+        let certificate_issuer = extension(
+            &OID_X509_EXT_ISSUER,
+            true,
+            &seq(&[&der(0xa4, &name("Other CA"))]),
+        );
+        let revoked = || vec![revoked_entry(&[1], &[]), revoked_entry(&[2], &[])];
+
+        let fields = [&distribution_point[..], &only_user_certs];
+        assert!(idp_failures(true, &fields, revoked()).is_empty());
+        let revoked_indirect = vec![
+            revoked_entry(&[1], &[certificate_issuer]),
+            revoked_entry(&[2], &[]),
+        ];
+        let fields = [&distribution_point[..], &indirect_crl];
+        assert!(idp_failures(true, &fields, revoked_indirect).is_empty());
+
+        let fields = [&distribution_point[..], &only_user_certs, &only_ca_certs];
+        assert_eq!(
+            idp_failures(false, &fields, revoked()),
+            [
+                "rfc:crl_idp_not_critical",
+                "rfc:crl_idp_only_contains_exclusive",
+            ]
+        );
+        assert_eq!(idp_failures(true, &[], revoked()), ["rfc:crl_idp_empty"]);
+        let fields = [&distribution_point[..], &indirect_crl];
+        assert_eq!(
+            idp_failures(true, &fields, revoked()),
+            ["rfc:crl_idp_indirect_no_certificate_issuer"]
+        );
+    }
+}
@@ -0,0 +1,277 @@
+use sha1::{Digest, Sha1};
+use sha2::{Sha256, Sha384, Sha512};
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::{
+    OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER, OID_X509_EXT_SUBJECT_KEY_IDENTIFIER,
+};
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+use x509_parser::x509::SubjectPublicKeyInfo;
+
+use super::helpers::is_self_issued;
+use crate::*;
+
+pub(crate) const KEY_IDENTIFIER_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_AKI_MISSING, cert_ext_aki_missing),
+    (CERT_EXT_AKI_CRITICAL, cert_ext_aki_critical),
+    (CERT_EXT_AKI_NO_KEYID, cert_ext_aki_no_keyid),
+    (CERT_EXT_SKI_MISSING_CA, cert_ext_ski_missing_ca),
+    (CERT_EXT_SKI_CRITICAL, cert_ext_ski_critical),
+    (CERT_EXT_SKI_NOT_DERIVED, cert_ext_ski_not_derived),
+];
+
+pub(crate) const CRL_KEY_IDENTIFIER_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_EXT_AKI_MISSING, crl_ext_aki_missing),
+    (CRL_EXT_AKI_CRITICAL, crl_ext_aki_critical),
+    (CRL_EXT_AKI_NO_KEYID, crl_ext_aki_no_keyid),
+];
+
+lint_definition!(
+    CERT_EXT_AKI_MISSING,
+    "rfc:cert_ext_aki_missing",
+    "The AuthorityKeyIdentifier extension MUST be included in all certificates, except self-signed",
+    "RFC5280: 4.2.1.1"
+);
+/// Signatures are not verified, so self-signed certificates cannot be distinguished from other
+/// self-issued certificates (for ex. key rollover certificates): a missing extension is only a
+/// warning for all self-issued certificates.
+pub(super) fn cert_ext_aki_missing(x509: &X509Certificate) -> LintResult {
+    if x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER)
+    {
+        return LintResult::pass();
+    }
+    if is_self_issued(x509) {
+        LintResult::new(LintStatus::Warn)
+    } else {
+        LintResult::new(LintStatus::Error)
+    }
+}
+
+lint_definition!(
+    CERT_EXT_AKI_CRITICAL,
+    "rfc:cert_ext_aki_critical",
+    "Conforming CAs MUST mark the AuthorityKeyIdentifier extension as non-critical",
+    "RFC5280: 4.2.1.1"
+);
+certificate_lint!(
+    pub(super) cert_ext_aki_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER && ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_AKI_NO_KEYID,
+    "rfc:cert_ext_aki_no_keyid",
+    "The keyIdentifier field of the AuthorityKeyIdentifier extension should be included",
+    "RFC5280: 4.2.1.1"
+);
+pub(super) fn cert_ext_aki_no_keyid(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::AuthorityKeyIdentifier(aki) = ext.parsed_extension() {
+            if aki.key_identifier.is_none() {
+                return LintResult::new(LintStatus::Warn);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SKI_MISSING_CA,
+    "rfc:cert_ext_ski_missing_ca",
+    "The SubjectKeyIdentifier extension MUST appear in all conforming CA certificates",
+    "RFC5280: 4.2.1.2"
+);
+certificate_lint!(
+    pub(super) cert_ext_ski_missing_ca,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509.is_ca()
+        && !x509
+            .iter_extensions()
+            .any(|ext| ext.oid == OID_X509_EXT_SUBJECT_KEY_IDENTIFIER)
+);
+
+lint_definition!(
+    CERT_EXT_SKI_CRITICAL,
+    "rfc:cert_ext_ski_critical",
+    "Conforming CAs MUST mark the SubjectKeyIdentifier extension as non-critical",
+    "RFC5280: 4.2.1.2"
+);
+certificate_lint!(
+    pub(super) cert_ext_ski_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_SUBJECT_KEY_IDENTIFIER && ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_SKI_NOT_DERIVED,
+    "rfc:cert_ext_ski_not_derived",
+    "SubjectKeyIdentifier is not derived from the public key using a known method",
+    "RFC5280: 4.2.1.2, RFC7093: 2"
+);
+pub(super) fn cert_ext_ski_not_derived(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::SubjectKeyIdentifier(ski) = ext.parsed_extension() {
+            let candidates = key_identifier_candidates(x509.public_key());
+            if !candidates.iter().any(|c| c.as_slice() == ski.0) {
+                let details = LintDetails::new(format!("{ski:x}"));
+                return LintResult::new_details(LintStatus::Warn, details);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+/// Compute the key identifiers which can be derived from the public key
+///
+/// Methods are the ones described in RFC5280 section 4.2.1.2 (SHA-1 of the key,
+/// truncated SHA-1 with type `0100`), and RFC7093 section 2 (truncated SHA-256, SHA-384
+/// and SHA-512 of the key).
+fn key_identifier_candidates(spki: &SubjectPublicKeyInfo) -> [Vec<u8>; 5] {
+    let key = spki.subject_public_key.data.as_ref();
+    let sha1 = Sha1::digest(key).to_vec();
+    let mut sha1_truncated = sha1[12..].to_vec();
+    sha1_truncated[0] = 0x40 | (sha1_truncated[0] & 0x0f);
+    [
+        sha1,
+        sha1_truncated,
+        Sha256::digest(key)[..20].to_vec(),
+        Sha384::digest(key)[..20].to_vec(),
+        Sha512::digest(key)[..20].to_vec(),
+    ]
+}
+
+lint_definition!(
+    CRL_EXT_AKI_MISSING,
+    "rfc:crl_ext_aki_missing",
+    "Conforming CRL issuers MUST include the AuthorityKeyIdentifier extension in all CRLs",
+    "RFC5280: 5.2.1"
+);
+crl_lint!(
+    pub(super) crl_ext_aki_missing,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| !crl
+        .extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER)
+);
+
+lint_definition!(
+    CRL_EXT_AKI_CRITICAL,
+    "rfc:crl_ext_aki_critical",
+    "Conforming CRL issuers MUST mark the AuthorityKeyIdentifier extension as non-critical",
+    "RFC5280: 5.2.1"
+);
+crl_lint!(
+    pub(super) crl_ext_aki_critical,
+    LintStatus::Error,
+    |crl: &CertificateRevocationList| crl
+        .extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER && ext.critical)
+);
+
+lint_definition!(
+    CRL_EXT_AKI_NO_KEYID,
+    "rfc:crl_ext_aki_no_keyid",
+    "Conforming CRL issuers MUST use the key identifier method in the AuthorityKeyIdentifier",
+    "RFC5280: 5.2.1"
+);
+pub(super) fn crl_ext_aki_no_keyid(crl: &CertificateRevocationList) -> LintResult {
+    for ext in crl.extensions() {
+        if let ParsedExtension::AuthorityKeyIdentifier(aki) = ext.parsed_extension() {
+            if aki.key_identifier.is_none() {
+                return LintResult::new(LintStatus::Error);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn aki(critical: bool, key_identifier: bool) -> Vec<u8> {
+        let value = if key_identifier {
+            seq(&[&der(0x80, &[0x5a; 20])])
+        } else {
+            seq(&[&der(0x82, &[0x01])])
+        };
+        extension(&OID_X509_EXT_AUTHORITY_KEY_IDENTIFIER, critical, &value)
+    }
+
+    #[test]
+    fn ski_derivation() {
+        let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("assets/IGC_A.der");
+        let data = std::fs::read(path).expect("DER file not found");
+        let (_, x509) = X509Certificate::from_der(&data).expect("Could not parse certificate");
+
+        let ski = x509
+            .extensions()
+            .iter()
+            .find_map(|ext| match ext.parsed_extension() {
+                ParsedExtension::SubjectKeyIdentifier(ski) => Some(ski.0),
+                _ => None,
+            })
+            .expect("missing SKI");
+        let candidates = key_identifier_candidates(x509.public_key());
+        assert!(candidates.iter().any(|c| c.as_slice() == ski));
+        assert_eq!(cert_ext_ski_not_derived(&x509).status, LintStatus::Pass);
+    }
+
+    #[test]
+    fn certificate_aki() {
+        let data = CertificateBuilder::with_extensions(vec![aki(false, true)]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(KEY_IDENTIFIER_LINTS, &x509).is_empty());
+
+        let data = CertificateBuilder::default().build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(cert_ext_aki_missing(&x509).status, LintStatus::Error);
+        // self-issued, but possibly not self-signed
+        let data = CertificateBuilder {
+            subject: name("Test CA"),
+            ..Default::default()
+        }
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(cert_ext_aki_missing(&x509).status, LintStatus::Warn);
+
+        let data = CertificateBuilder::with_extensions(vec![aki(true, true)]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(KEY_IDENTIFIER_LINTS, &x509),
+            ["rfc:cert_ext_aki_critical"]
+        );
+        let data = CertificateBuilder::with_extensions(vec![aki(false, false)]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(KEY_IDENTIFIER_LINTS, &x509),
+            ["rfc:cert_ext_aki_no_keyid"]
+        );
+    }
+
+    #[test]
+    fn crl_aki() {
+        for (extensions, expected) in [
+            (vec![aki(false, true)], &[][..]),
+            (Vec::new(), &["rfc:crl_ext_aki_missing"]),
+            (vec![aki(true, true)], &["rfc:crl_ext_aki_critical"]),
+            (vec![aki(false, false)], &["rfc:crl_ext_aki_no_keyid"]),
+        ] {
+            let data = CrlBuilder::with_extensions(extensions).build();
+            let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+            assert_eq!(crl_lint_failures(CRL_KEY_IDENTIFIER_LINTS, &crl), expected);
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Weak RSA keys which must be rejected by CAs (CABF BR 6.1.1.3)
+//!
+//! Shared primes between moduli can only be detected on a corpus of keys (batch GCD), and are
+//! not checked here.
+
+use sha1::{Digest, Sha1};
+use x509_parser::num_bigint::BigUint;
+use x509_parser::prelude::X509Certificate;
+
+use super::helpers::strip_leading_zeroes;
+use super::spki::rsa_public_key;
+use crate::*;
+
+pub(crate) const KEY_QUALITY_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (SPKI_RSA_ROCA, spki_rsa_roca),
+    (SPKI_RSA_SMALL_FACTOR, spki_rsa_small_factor),
+    (SPKI_RSA_FERMAT, spki_rsa_fermat),
+];
+
+pub(crate) const KEY_QUALITY_CONFIGURABLE_LINTS: &[(
+    LintDefinition,
+    ConfigurableCertificateLint,
+)] = &[(SPKI_RSA_DEBIAN_WEAK_KEY, spki_rsa_debian_weak_key)];
+
+/// Largest prime used by the ROCA fingerprint
+const ROCA_MAX_PRIME: u32 = 167;
+
+/// Moduli SHOULD have no factors smaller than this value
+const SMALL_FACTOR_BOUND: u32 = 752;
+
+/// Number of rounds of Fermat factorization
+const FERMAT_ROUNDS: usize = 100;
+
+/// Odd primes smaller than or equal to `max`
+fn odd_primes(max: u32) -> impl Iterator<Item = u32> {
+    (3..=max).step_by(2).filter(|&n| {
+        (3..)
+            .step_by(2)
+            .take_while(|d| d * d <= n)
+            .all(|d| n % d != 0)
+    })
+}
+
+/// Remainder of the division of a big-endian unsigned integer by `d`
+fn modulo(n: &[u8], d: u32) -> u32 {
+    n.iter()
+        .fold(0u64, |r, &b| ((r << 8) | u64::from(b)) % u64::from(d)) as u32
+}
+
+/// Return true if the modulus has the fingerprint of keys generated by vulnerable Infineon
+/// libraries (CVE-2017-15361)
+///
+/// Vulnerable moduli have the form `k * M + (65537^a mod M)`, where `M` is a primorial: for each
+/// small prime `p`, `n mod p` belongs to the subgroup generated by 65537.
+fn is_roca_vulnerable(n: &[u8]) -> bool {
+    odd_primes(ROCA_MAX_PRIME).all(|p| {
+        let r = modulo(n, p);
+        let generator = 65537 % p;
+        let mut x = 1;
+        loop {
+            if x == r {
+                return true;
+            }
+            x = x * generator % p;
+            if x == 1 {
+                return false;
+            }
+        }
+    })
+}
+
+/// Fingerprint used by the Debian weak keys blocklists (`openssl-blacklist` format)
+fn debian_fingerprint(n: &[u8]) -> String {
+    let hex: String = n.iter().map(|b| format!("{b:02X}")).collect();
+    let digest = Sha1::digest(format!("Modulus={hex}\n").as_bytes());
+    let digest: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    digest[20..].to_string()
+}
+
+/// Return the smallest prime factor of the modulus smaller than `SMALL_FACTOR_BOUND`, if any
+fn small_factor(n: &[u8]) -> Option<u32> {
+    if n.last().is_some_and(|b| b & 1 == 0) {
+        return Some(2);
+    }
+    odd_primes(SMALL_FACTOR_BOUND - 1).find(|&p| modulo(n, p) == 0)
+}
+
+/// Try to factor the modulus using Fermat's method, which succeeds quickly if `p` and `q`
+/// are close
+fn fermat_factor(n: &BigUint, rounds: usize) -> Option<(BigUint, BigUint)> {
+    let mut a = n.sqrt();
+    if &a * &a < *n {
+        a += 1u32;
+    }
+    for _ in 0..rounds {
+        let b2 = &a * &a - n;
+        let b = b2.sqrt();
+        if &b * &b == b2 {
+            return Some((&a - &b, &a + &b));
+        }
+        a += 1u32;
+    }
+    None
+}
+
+lint_definition!(
+    SPKI_RSA_ROCA,
+    "rfc:spki_rsa_roca",
+    "RSA modulus has the fingerprint of ROCA vulnerable keys (CVE-2017-15361)",
+    "CABF BR: 6.1.1.3"
+);
+certificate_lint!(
+    pub(super) spki_rsa_roca,
+    LintStatus::Error,
+    |x509: &X509Certificate| rsa_public_key(x509)
+        .is_some_and(|rsa| is_roca_vulnerable(strip_leading_zeroes(rsa.modulus)))
+);
+
+lint_definition!(
+    SPKI_RSA_DEBIAN_WEAK_KEY,
+    "rfc:spki_rsa_debian_weak_key",
+    "RSA key is a Debian weak key (CVE-2008-0166)",
+    "CABF BR: 6.1.1.3"
+);
+pub(super) fn spki_rsa_debian_weak_key(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    let Some(rsa) = rsa_public_key(x509) else {
+        return LintResult::pass();
+    };
+    let fingerprint = debian_fingerprint(strip_leading_zeroes(rsa.modulus));
+    if config.debian_weak_keys.contains(&fingerprint) {
+        return LintResult::new_details(LintStatus::Error, LintDetails::new(fingerprint));
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    SPKI_RSA_SMALL_FACTOR,
+    "rfc:spki_rsa_small_factor",
+    "RSA modulus SHOULD have no factors smaller than 752",
+    "CABF BR: 6.1.6"
+);
+pub(super) fn spki_rsa_small_factor(x509: &X509Certificate) -> LintResult {
+    let Some(rsa) = rsa_public_key(x509) else {
+        return LintResult::pass();
+    };
+    match small_factor(strip_leading_zeroes(rsa.modulus)) {
+        Some(p) => LintResult::new_details(LintStatus::Warn, LintDetails::new(p.to_string())),
+        None => LintResult::pass(),
+    }
+}
+
+lint_definition!(
+    SPKI_RSA_FERMAT,
+    "rfc:spki_rsa_fermat",
+    "RSA modulus can be factored using Fermat's method (close primes)",
+    "CABF BR: 6.1.1.3"
+);
+certificate_lint!(
+    pub(super) spki_rsa_fermat,
+    LintStatus::Error,
+    |x509: &X509Certificate| rsa_public_key(x509).is_some_and(|rsa| {
+        let n = BigUint::from_bytes_be(rsa.modulus);
+        n.bits() > 1 && fermat_factor(&n, FERMAT_ROUNDS).is_some()
+    })
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rsa_key_quality() {
+        assert_eq!(
+            odd_primes(20).collect::<Vec<_>>(),
+            [3, 5, 7, 11, 13, 17, 19]
+        );
+        // 1009 * 1013 (close primes) and 3 * 1009
+        let n = BigUint::from(1_022_117u32);
+        assert_eq!(
+            fermat_factor(&n, 10),
+            Some((BigUint::from(1009u32), BigUint::from(1013u32)))
+        );
+        assert_eq!(small_factor(&1_022_117u32.to_be_bytes()), None);
+        assert_eq!(small_factor(&3027u32.to_be_bytes()), Some(3));
+        // 65537^k mod M for the primorial M is always ROCA-like, 65539 (prime) is not
+        assert!(is_roca_vulnerable(&65537u32.to_be_bytes()));
+        assert!(!is_roca_vulnerable(&65539u32.to_be_bytes()));
+    }
+}
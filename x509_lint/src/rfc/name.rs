@@ -1,6 +1,10 @@
-use x509_parser::asn1_rs::Tag;
-use x509_parser::prelude::X509Certificate;
+use x509_parser::asn1_rs::{Any, Oid, Tag};
+use x509_parser::objects::{oid2sn, oid_registry};
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+use x509_parser::x509::{AttributeTypeAndValue, X509Name};
 
+use super::oids::OID_X509_PSEUDONYM;
 use crate::*;
 
 pub(crate) const NAME_LINTS: &[(LintDefinition, CertificateLint)] = &[
@@ -9,8 +13,295 @@ pub(crate) const NAME_LINTS: &[(LintDefinition, CertificateLint)] = &[
         subject_cn_not_printablestring,
     ),
     (ISSUER_EMPTY, issuer_empty),
+    (
+        NAME_DIRECTORYSTRING_INVALID_TYPE,
+        name_directorystring_invalid_type,
+    ),
+    (NAME_DIRECTORYSTRING_NOT_UTF8, name_directorystring_not_utf8),
+    (NAME_ATTRIBUTE_TOO_LONG, name_attribute_too_long),
+    (
+        NAME_COUNTRYNAME_INVALID_LENGTH,
+        name_countryname_invalid_length,
+    ),
+    (
+        NAME_ATTRIBUTE_NOT_PRINTABLESTRING,
+        name_attribute_not_printablestring,
+    ),
+    (NAME_DC_NOT_IA5STRING, name_dc_not_ia5string),
+    (NAME_EMAIL_INVALID, name_email_invalid),
+    (
+        NAME_PRINTABLESTRING_INVALID_CHARS,
+        name_printablestring_invalid_chars,
+    ),
+    (NAME_ATTRIBUTE_EMPTY, name_attribute_empty),
+    (NAME_ATTRIBUTE_WHITESPACE, name_attribute_whitespace),
+    (NAME_MULTIVALUED_RDN, name_multivalued_rdn),
+    (NAME_DUPLICATE_ATTRIBUTE, name_duplicate_attribute),
+    (SUBJECT_EMAIL_ADDRESS, subject_email_address),
+    (SUBJECT_EMPTY_WITHOUT_SAN, subject_empty_without_san),
 ];
 
+pub(crate) const CRL_NAME_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (
+        CRL_NAME_DIRECTORYSTRING_INVALID_TYPE,
+        crl_name_directorystring_invalid_type,
+    ),
+    (
+        CRL_NAME_DIRECTORYSTRING_NOT_UTF8,
+        crl_name_directorystring_not_utf8,
+    ),
+    (CRL_NAME_ATTRIBUTE_TOO_LONG, crl_name_attribute_too_long),
+    (
+        CRL_NAME_COUNTRYNAME_INVALID_LENGTH,
+        crl_name_countryname_invalid_length,
+    ),
+    (
+        CRL_NAME_ATTRIBUTE_NOT_PRINTABLESTRING,
+        crl_name_attribute_not_printablestring,
+    ),
+    (CRL_NAME_DC_NOT_IA5STRING, crl_name_dc_not_ia5string),
+    (CRL_NAME_EMAIL_INVALID, crl_name_email_invalid),
+    (
+        CRL_NAME_PRINTABLESTRING_INVALID_CHARS,
+        crl_name_printablestring_invalid_chars,
+    ),
+    (CRL_NAME_ATTRIBUTE_EMPTY, crl_name_attribute_empty),
+    (CRL_NAME_ATTRIBUTE_WHITESPACE, crl_name_attribute_whitespace),
+    (CRL_NAME_MULTIVALUED_RDN, crl_name_multivalued_rdn),
+    (CRL_NAME_DUPLICATE_ATTRIBUTE, crl_name_duplicate_attribute),
+];
+
+/// Attributes of type DirectoryString, and their upper bound (from RFC5280 Appendix A)
+const DIRECTORYSTRING_ATTRIBUTES: &[(Oid<'static>, usize)] = &[
+    (OID_X509_NAME, 32768),
+    (OID_X509_COMMON_NAME, 64),
+    (OID_X509_SURNAME, 32768),
+    (OID_X509_GIVEN_NAME, 32768),
+    (OID_X509_INITIALS, 32768),
+    (OID_X509_GENERATION_QUALIFIER, 32768),
+    (OID_X509_LOCALITY_NAME, 128),
+    (OID_X509_STATE_OR_PROVINCE_NAME, 128),
+    (OID_X509_ORGANIZATION_NAME, 64),
+    (OID_X509_ORGANIZATIONAL_UNIT, 64),
+    (OID_X509_TITLE, 64),
+    (OID_X509_PSEUDONYM, 128),
+];
+
+/// Attributes which are expected to appear more than once in a name
+const REPEATABLE_ATTRIBUTES: &[Oid<'static>] = &[
+    OID_DOMAIN_COMPONENT,
+    OID_X509_ORGANIZATIONAL_UNIT,
+    OID_X509_STREET_ADDRESS,
+];
+
+/// Upper bounds of attributes which are not a DirectoryString
+const OTHER_UPPER_BOUNDS: &[(Oid<'static>, usize)] =
+    &[(OID_X509_SERIALNUMBER, 64), (OID_PKCS9_EMAIL_ADDRESS, 255)];
+
+/// Adapt an attribute check to run on all attributes of a name
+fn each_attribute(
+    check: impl Fn(&AttributeTypeAndValue) -> Result<(), String>,
+) -> impl Fn(&X509Name) -> Result<(), String> {
+    move |name| name.iter_attributes().try_for_each(&check)
+}
+
+/// Run `check` on the subject and issuer names
+fn check_cert_names(
+    x509: &X509Certificate,
+    status: LintStatus,
+    check: impl Fn(&X509Name) -> Result<(), String>,
+) -> LintResult {
+    for (field, name) in [("subject", x509.subject()), ("issuer", x509.issuer())] {
+        if let Err(e) = check(name) {
+            return LintResult::new_details(status, LintDetails::new(format!("{field}: {e}")));
+        }
+    }
+    LintResult::pass()
+}
+
+/// Run `check` on the CRL issuer name
+fn check_crl_name(
+    crl: &CertificateRevocationList,
+    status: LintStatus,
+    check: impl Fn(&X509Name) -> Result<(), String>,
+) -> LintResult {
+    match check(crl.issuer()) {
+        Ok(()) => LintResult::pass(),
+        Err(e) => LintResult::new_details(status, LintDetails::new(format!("issuer: {e}"))),
+    }
+}
+
+/// Short name of the attribute type, for details
+fn attr_name(attr: &AttributeTypeAndValue) -> String {
+    oid2sn(attr.attr_type(), oid_registry())
+        .map(str::to_string)
+        .unwrap_or_else(|_| attr.attr_type().to_id_string())
+}
+
+/// Number of characters of a string value
+fn char_count(value: &Any) -> usize {
+    match value.tag() {
+        Tag::BmpString => value.data.len() / 2,
+        Tag::UniversalString => value.data.len() / 4,
+        Tag::Utf8String => {
+            std::str::from_utf8(value.data).map_or(value.data.len(), |s| s.chars().count())
+        }
+        _ => value.data.len(),
+    }
+}
+
+fn is_printable_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b" '()+,-./:=?".contains(&b)
+}
+
+fn is_directorystring(attr: &AttributeTypeAndValue) -> bool {
+    DIRECTORYSTRING_ATTRIBUTES
+        .iter()
+        .any(|(oid, _)| oid == attr.attr_type())
+}
+
+fn check_directorystring_type(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    match attr.attr_value().tag() {
+        Tag::TeletexString
+        | Tag::PrintableString
+        | Tag::UniversalString
+        | Tag::Utf8String
+        | Tag::BmpString => Ok(()),
+        _ if !is_directorystring(attr) => Ok(()),
+        tag => Err(format!("{} encoded as {tag}", attr_name(attr))),
+    }
+}
+
+fn check_directorystring_utf8(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    match attr.attr_value().tag() {
+        Tag::PrintableString | Tag::Utf8String => Ok(()),
+        Tag::TeletexString | Tag::UniversalString | Tag::BmpString if is_directorystring(attr) => {
+            Err(format!(
+                "{} encoded as {}",
+                attr_name(attr),
+                attr.attr_value().tag()
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_attribute_length(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    let bound = DIRECTORYSTRING_ATTRIBUTES
+        .iter()
+        .chain(OTHER_UPPER_BOUNDS)
+        .find_map(|(oid, ub)| (oid == attr.attr_type()).then_some(*ub));
+    match bound {
+        Some(ub) if char_count(attr.attr_value()) > ub => Err(format!(
+            "{} is longer than {ub} characters",
+            attr_name(attr)
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_countryname_length(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    if *attr.attr_type() == OID_X509_COUNTRY_NAME && char_count(attr.attr_value()) != 2 {
+        return Err(format!(
+            "invalid countryName '{}'",
+            attr.as_str().unwrap_or("?")
+        ));
+    }
+    Ok(())
+}
+
+fn check_printablestring_attribute(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    let printable_only = [OID_X509_SERIALNUMBER, OID_X509_DN_QUALIFIER];
+    if printable_only.contains(attr.attr_type()) && attr.attr_value().tag() != Tag::PrintableString
+    {
+        return Err(format!(
+            "{} encoded as {}",
+            attr_name(attr),
+            attr.attr_value().tag()
+        ));
+    }
+    Ok(())
+}
+
+fn check_dc_ia5string(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    if *attr.attr_type() == OID_DOMAIN_COMPONENT && attr.attr_value().tag() != Tag::Ia5String {
+        return Err(format!(
+            "domainComponent encoded as {}",
+            attr.attr_value().tag()
+        ));
+    }
+    Ok(())
+}
+
+fn check_email(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    if *attr.attr_type() != OID_PKCS9_EMAIL_ADDRESS {
+        return Ok(());
+    }
+    let value = attr.attr_value();
+    if value.tag() != Tag::Ia5String {
+        return Err(format!("emailAddress encoded as {}", value.tag()));
+    }
+    // the length is checked by name_attribute_too_long
+    Ok(())
+}
+
+fn check_printablestring_chars(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    let value = attr.attr_value();
+    if value.tag() == Tag::PrintableString && !value.data.iter().all(|&b| is_printable_char(b)) {
+        return Err(format!(
+            "{}: invalid character in '{}'",
+            attr_name(attr),
+            String::from_utf8_lossy(value.data)
+        ));
+    }
+    Ok(())
+}
+
+fn check_attribute_empty(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    if attr.attr_value().data.is_empty() {
+        return Err(format!("empty {}", attr_name(attr)));
+    }
+    Ok(())
+}
+
+fn check_attribute_whitespace(attr: &AttributeTypeAndValue) -> Result<(), String> {
+    let Ok(s) = attr.as_str() else {
+        return Ok(());
+    };
+    if s.trim().is_empty() && !s.is_empty() {
+        return Err(format!("{} contains only whitespace", attr_name(attr)));
+    }
+    if s != s.trim() {
+        return Err(format!(
+            "{} '{s}' has leading or trailing whitespace",
+            attr_name(attr)
+        ));
+    }
+    Ok(())
+}
+
+fn check_multivalued_rdn(name: &X509Name) -> Result<(), String> {
+    for rdn in name.iter_rdn() {
+        if rdn.iter().count() > 1 {
+            let types: Vec<_> = rdn.iter().map(attr_name).collect();
+            return Err(format!("multi-valued RDN {}", types.join("+")));
+        }
+    }
+    Ok(())
+}
+
+fn check_duplicate_attributes(name: &X509Name) -> Result<(), String> {
+    let mut seen = Vec::new();
+    for attr in name.iter_attributes() {
+        let oid = attr.attr_type();
+        if seen.contains(&oid) && !REPEATABLE_ATTRIBUTES.contains(oid) {
+            return Err(format!("duplicate {}", attr_name(attr)));
+        }
+        seen.push(oid);
+    }
+    Ok(())
+}
+
 lint_definition!(
     SUBJECT_CN_NOT_PRINTABLESTRING,
     "rfc:subject_countryname_not_printablestring",
@@ -42,3 +333,474 @@ pub(super) fn issuer_empty(x509: &X509Certificate) -> LintResult {
 
     LintResult::pass()
 }
+
+lint_definition!(
+    NAME_DIRECTORYSTRING_INVALID_TYPE,
+    "rfc:name_directorystring_invalid_type",
+    "DirectoryString attribute is not encoded using one of the allowed string types",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_directorystring_invalid_type(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Error,
+        each_attribute(check_directorystring_type),
+    )
+}
+
+lint_definition!(
+    NAME_DIRECTORYSTRING_NOT_UTF8,
+    "rfc:name_directorystring_not_utf8",
+    "Conforming CAs MUST use either the PrintableString or UTF8String encoding of DirectoryString",
+    "RFC5280: 4.1.2.6"
+);
+pub(super) fn name_directorystring_not_utf8(x509: &X509Certificate) -> LintResult {
+    // some exceptions exist for backwards compatibility, so this is only a warning
+    check_cert_names(
+        x509,
+        LintStatus::Warn,
+        each_attribute(check_directorystring_utf8),
+    )
+}
+
+lint_definition!(
+    NAME_ATTRIBUTE_TOO_LONG,
+    "rfc:name_attribute_too_long",
+    "DN attribute value exceeds its upper bound",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_attribute_too_long(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Error,
+        each_attribute(check_attribute_length),
+    )
+}
+
+lint_definition!(
+    NAME_COUNTRYNAME_INVALID_LENGTH,
+    "rfc:name_countryname_invalid_length",
+    "CountryName MUST be a two-letter country code",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_countryname_invalid_length(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Error,
+        each_attribute(check_countryname_length),
+    )
+}
+
+lint_definition!(
+    NAME_ATTRIBUTE_NOT_PRINTABLESTRING,
+    "rfc:name_attribute_not_printablestring",
+    "SerialNumber and dnQualifier MUST be encoded as PrintableString",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_attribute_not_printablestring(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Error,
+        each_attribute(check_printablestring_attribute),
+    )
+}
+
+lint_definition!(
+    NAME_DC_NOT_IA5STRING,
+    "rfc:name_dc_not_ia5string",
+    "DomainComponent MUST be encoded as IA5String",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_dc_not_ia5string(x509: &X509Certificate) -> LintResult {
+    check_cert_names(x509, LintStatus::Error, each_attribute(check_dc_ia5string))
+}
+
+lint_definition!(
+    NAME_EMAIL_INVALID,
+    "rfc:name_email_invalid",
+    "EmailAddress MUST be an IA5String",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_email_invalid(x509: &X509Certificate) -> LintResult {
+    check_cert_names(x509, LintStatus::Error, each_attribute(check_email))
+}
+
+lint_definition!(
+    NAME_PRINTABLESTRING_INVALID_CHARS,
+    "rfc:name_printablestring_invalid_chars",
+    "Invalid character in PrintableString DN attribute",
+    "X.680: 41.4"
+);
+pub(super) fn name_printablestring_invalid_chars(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Error,
+        each_attribute(check_printablestring_chars),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_DIRECTORYSTRING_INVALID_TYPE,
+    "rfc:crl_name_directorystring_invalid_type",
+    "DirectoryString attribute is not encoded using one of the allowed string types",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_directorystring_invalid_type(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Error,
+        each_attribute(check_directorystring_type),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_DIRECTORYSTRING_NOT_UTF8,
+    "rfc:crl_name_directorystring_not_utf8",
+    "Conforming CAs MUST use either the PrintableString or UTF8String encoding of DirectoryString",
+    "RFC5280: 4.1.2.6"
+);
+pub(super) fn crl_name_directorystring_not_utf8(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Warn,
+        each_attribute(check_directorystring_utf8),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_ATTRIBUTE_TOO_LONG,
+    "rfc:crl_name_attribute_too_long",
+    "DN attribute value exceeds its upper bound",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_attribute_too_long(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Error,
+        each_attribute(check_attribute_length),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_COUNTRYNAME_INVALID_LENGTH,
+    "rfc:crl_name_countryname_invalid_length",
+    "CountryName MUST be a two-letter country code",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_countryname_invalid_length(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Error,
+        each_attribute(check_countryname_length),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_ATTRIBUTE_NOT_PRINTABLESTRING,
+    "rfc:crl_name_attribute_not_printablestring",
+    "SerialNumber and dnQualifier MUST be encoded as PrintableString",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_attribute_not_printablestring(
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Error,
+        each_attribute(check_printablestring_attribute),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_DC_NOT_IA5STRING,
+    "rfc:crl_name_dc_not_ia5string",
+    "DomainComponent MUST be encoded as IA5String",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_dc_not_ia5string(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(crl, LintStatus::Error, each_attribute(check_dc_ia5string))
+}
+
+lint_definition!(
+    CRL_NAME_EMAIL_INVALID,
+    "rfc:crl_name_email_invalid",
+    "EmailAddress MUST be an IA5String",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_email_invalid(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(crl, LintStatus::Error, each_attribute(check_email))
+}
+
+lint_definition!(
+    CRL_NAME_PRINTABLESTRING_INVALID_CHARS,
+    "rfc:crl_name_printablestring_invalid_chars",
+    "Invalid character in PrintableString DN attribute",
+    "X.680: 41.4"
+);
+pub(super) fn crl_name_printablestring_invalid_chars(
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Error,
+        each_attribute(check_printablestring_chars),
+    )
+}
+
+lint_definition!(
+    NAME_ATTRIBUTE_EMPTY,
+    "rfc:name_attribute_empty",
+    "DN attribute value MUST NOT be empty",
+    "RFC5280: Appendix A"
+);
+pub(super) fn name_attribute_empty(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Error,
+        each_attribute(check_attribute_empty),
+    )
+}
+
+lint_definition!(
+    NAME_ATTRIBUTE_WHITESPACE,
+    "rfc:name_attribute_whitespace",
+    "DN attribute value is whitespace only, or has leading or trailing whitespace"
+);
+pub(super) fn name_attribute_whitespace(x509: &X509Certificate) -> LintResult {
+    check_cert_names(
+        x509,
+        LintStatus::Warn,
+        each_attribute(check_attribute_whitespace),
+    )
+}
+
+lint_definition!(
+    NAME_MULTIVALUED_RDN,
+    "rfc:name_multivalued_rdn",
+    "DN contains a multi-valued RelativeDistinguishedName",
+    "CABF BR: 7.1.4"
+);
+pub(super) fn name_multivalued_rdn(x509: &X509Certificate) -> LintResult {
+    check_cert_names(x509, LintStatus::Warn, check_multivalued_rdn)
+}
+
+lint_definition!(
+    NAME_DUPLICATE_ATTRIBUTE,
+    "rfc:name_duplicate_attribute",
+    "DN contains more than one instance of an attribute type",
+    "CABF BR: 7.1.4"
+);
+pub(super) fn name_duplicate_attribute(x509: &X509Certificate) -> LintResult {
+    check_cert_names(x509, LintStatus::Warn, check_duplicate_attributes)
+}
+
+lint_definition!(
+    SUBJECT_EMAIL_ADDRESS,
+    "rfc:subject_email_address",
+    "Subject DN: new certificates MUST use rfc822Name in subjectAltName instead of emailAddress",
+    "RFC5280: 4.1.2.6"
+);
+certificate_lint!(
+    pub(super) subject_email_address,
+    LintStatus::Warn,
+    |x509: &X509Certificate| x509.subject().iter_email().next().is_some()
+);
+
+lint_definition!(
+    SUBJECT_EMPTY_WITHOUT_SAN,
+    "rfc:subject_empty_without_san",
+    "If the subject field contains an empty sequence, a subjectAltName extension MUST be present",
+    "RFC5280: 4.1.2.6"
+);
+certificate_lint!(
+    pub(super) subject_empty_without_san,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509.subject().iter().count() == 0
+        && !x509
+            .iter_extensions()
+            .any(|ext| ext.oid == OID_X509_EXT_SUBJECT_ALT_NAME)
+);
+
+lint_definition!(
+    CRL_NAME_ATTRIBUTE_EMPTY,
+    "rfc:crl_name_attribute_empty",
+    "DN attribute value MUST NOT be empty",
+    "RFC5280: Appendix A"
+);
+pub(super) fn crl_name_attribute_empty(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Error,
+        each_attribute(check_attribute_empty),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_ATTRIBUTE_WHITESPACE,
+    "rfc:crl_name_attribute_whitespace",
+    "DN attribute value is whitespace only, or has leading or trailing whitespace"
+);
+pub(super) fn crl_name_attribute_whitespace(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(
+        crl,
+        LintStatus::Warn,
+        each_attribute(check_attribute_whitespace),
+    )
+}
+
+lint_definition!(
+    CRL_NAME_MULTIVALUED_RDN,
+    "rfc:crl_name_multivalued_rdn",
+    "DN contains a multi-valued RelativeDistinguishedName",
+    "CABF BR: 7.1.4"
+);
+pub(super) fn crl_name_multivalued_rdn(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(crl, LintStatus::Warn, check_multivalued_rdn)
+}
+
+lint_definition!(
+    CRL_NAME_DUPLICATE_ATTRIBUTE,
+    "rfc:crl_name_duplicate_attribute",
+    "DN contains more than one instance of an attribute type",
+    "CABF BR: 7.1.4"
+);
+pub(super) fn crl_name_duplicate_attribute(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_name(crl, LintStatus::Warn, check_duplicate_attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    /// AttributeTypeAndValue, with the value encoded using `tag`
+    fn attribute(attr_type: &Oid, tag: u8, value: &str) -> Vec<u8> {
+        seq(&[&oid(attr_type), &der(tag, value.as_bytes())])
+    }
+
+    /// RelativeDistinguishedName containing a single attribute
+    fn rdn(attr_type: &Oid, tag: u8, value: &str) -> Vec<u8> {
+        set(&[&attribute(attr_type, tag, value)])
+    }
+
+    fn dn(rdns: &[Vec<u8>]) -> Vec<u8> {
+        der(0x30, &rdns.concat())
+    }
+
+    #[test]
+    fn name_lints() {
+        let data = CertificateBuilder::default().build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(NAME_LINTS, &x509).is_empty());
+        let data = CrlBuilder::default().build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert!(crl_lint_failures(CRL_NAME_LINTS, &crl).is_empty());
+
+        // attribute value encodings
+        let subject = dn(&[
+            rdn(&OID_X509_COUNTRY_NAME, 0x0c, "FR"),
+            rdn(&OID_X509_ORGANIZATION_NAME, 0x1e, "\0O"),
+            rdn(&OID_DOMAIN_COMPONENT, 0x13, "example"),
+            rdn(&OID_X509_SERIALNUMBER, 0x0c, "1234"),
+            rdn(&OID_X509_COMMON_NAME, 0x13, "www@example.com"),
+        ]);
+        let data = CertificateBuilder {
+            subject,
+            ..Default::default()
+        }
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(NAME_LINTS, &x509),
+            [
+                "rfc:subject_countryname_not_printablestring",
+                "rfc:name_directorystring_not_utf8",
+                "rfc:name_attribute_not_printablestring",
+                "rfc:name_dc_not_ia5string",
+                "rfc:name_printablestring_invalid_chars",
+            ]
+        );
+
+        // attribute values and name structure
+        let multivalued = set(&[
+            &attribute(&OID_X509_ORGANIZATION_NAME, 0x0c, "Example"),
+            &attribute(&OID_X509_ORGANIZATIONAL_UNIT, 0x0c, "Web"),
+        ]);
+        let subject = dn(&[
+            rdn(&OID_X509_COUNTRY_NAME, 0x13, "FRA"),
+            rdn(&OID_X509_COMMON_NAME, 0x0c, &"a".repeat(65)),
+            rdn(&OID_X509_ORGANIZATIONAL_UNIT, 0x0c, ""),
+            rdn(&OID_X509_LOCALITY_NAME, 0x0c, " Paris"),
+            rdn(&OID_PKCS9_EMAIL_ADDRESS, 0x0c, "a@example.com"),
+            rdn(&OID_X509_COMMON_NAME, 0x16, "www.example.com"),
+            multivalued,
+        ]);
+        let data = CertificateBuilder {
+            subject,
+            ..Default::default()
+        }
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(NAME_LINTS, &x509),
+            [
+                "rfc:name_directorystring_invalid_type",
+                "rfc:name_attribute_too_long",
+                "rfc:name_countryname_invalid_length",
+                "rfc:name_email_invalid",
+                "rfc:name_attribute_empty",
+                "rfc:name_attribute_whitespace",
+                "rfc:name_multivalued_rdn",
+                "rfc:name_duplicate_attribute",
+                "rfc:subject_email_address",
+            ]
+        );
+
+        // the length of emailAddress is only reported as an upper bound
+        let email = format!("{}@example.com", "a".repeat(244));
+        let subject = dn(&[rdn(&OID_PKCS9_EMAIL_ADDRESS, 0x16, &email)]);
+        let data = CertificateBuilder {
+            subject,
+            ..Default::default()
+        }
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(NAME_LINTS, &x509),
+            ["rfc:name_attribute_too_long", "rfc:subject_email_address"]
+        );
+
+        // empty issuer, and empty subject without subjectAltName
+        let data = CertificateBuilder {
+            issuer: seq(&[]),
+            subject: seq(&[]),
+            ..Default::default()
+        }
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(NAME_LINTS, &x509),
+            ["rfc:issuer_empty", "rfc:subject_empty_without_san"]
+        );
+
+        let issuer = dn(&[
+            rdn(&OID_X509_COMMON_NAME, 0x0c, "Test CA "),
+            rdn(&OID_X509_COMMON_NAME, 0x0c, "Test CA"),
+        ]);
+        let data = CrlBuilder {
+            issuer,
+            ..Default::default()
+        }
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert_eq!(
+            crl_lint_failures(CRL_NAME_LINTS, &crl),
+            [
+                "rfc:crl_name_attribute_whitespace",
+                "rfc:crl_name_duplicate_attribute",
+            ]
+        );
+    }
+}
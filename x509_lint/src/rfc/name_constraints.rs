@@ -0,0 +1,229 @@
+use x509_parser::asn1_rs::{Any, Class, FromDer};
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::OID_X509_EXT_NAME_CONSTRAINTS;
+use x509_parser::prelude::X509Certificate;
+
+use super::helpers::{check_dns_name, check_rfc822_name, der_items};
+use crate::*;
+
+pub(crate) const NAME_CONSTRAINTS_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_NC_NOT_CA, cert_ext_nc_not_ca),
+    (CERT_EXT_NC_NOT_CRITICAL, cert_ext_nc_not_critical),
+    (CERT_EXT_NC_EMPTY, cert_ext_nc_empty),
+    (CERT_EXT_NC_MINIMUM_MAXIMUM, cert_ext_nc_minimum_maximum),
+    (CERT_EXT_NC_INVALID_BASE, cert_ext_nc_invalid_base),
+];
+
+lint_definition!(
+    CERT_EXT_NC_NOT_CA,
+    "rfc:cert_ext_nc_not_ca",
+    "The NameConstraints extension MUST be used only in a CA certificate",
+    "RFC5280: 4.2.1.10"
+);
+certificate_lint!(
+    pub(super) cert_ext_nc_not_ca,
+    LintStatus::Error,
+    |x509: &X509Certificate| !x509.is_ca()
+        && x509
+            .iter_extensions()
+            .any(|ext| ext.oid == OID_X509_EXT_NAME_CONSTRAINTS)
+);
+
+lint_definition!(
+    CERT_EXT_NC_NOT_CRITICAL,
+    "rfc:cert_ext_nc_not_critical",
+    "Conforming CAs should mark the NameConstraints extension as critical",
+    "RFC5280: 4.2.1.10"
+);
+certificate_lint!(
+    pub(super) cert_ext_nc_not_critical,
+    LintStatus::Warn,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_NAME_CONSTRAINTS && !ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_NC_EMPTY,
+    "rfc:cert_ext_nc_empty",
+    "Conforming CAs MUST NOT issue certificates where name constraints is an empty sequence",
+    "RFC5280: 4.2.1.10"
+);
+pub(super) fn cert_ext_nc_empty(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::NameConstraints(nc) = ext.parsed_extension() {
+            if nc.permitted_subtrees.is_none() && nc.excluded_subtrees.is_none() {
+                return LintResult::new(LintStatus::Error);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_NC_MINIMUM_MAXIMUM,
+    "rfc:cert_ext_nc_minimum_maximum",
+    "Within NameConstraints, the minimum MUST be zero, and maximum MUST be absent",
+    "RFC5280: 4.2.1.10"
+);
+pub(super) fn cert_ext_nc_minimum_maximum(x509: &X509Certificate) -> LintResult {
+    // minimum and maximum are not provided by the parser, read them from the raw value
+    for ext in x509.iter_extensions() {
+        if ext.oid != OID_X509_EXT_NAME_CONSTRAINTS {
+            continue;
+        }
+        let Ok((_, seq)) = Any::from_der(ext.value) else {
+            continue;
+        };
+        // GeneralSubtrees are IMPLICIT [0] and [1]
+        for subtrees in der_items(seq.data) {
+            for subtree in der_items(subtrees.data) {
+                // skip base, then look for [0] minimum and [1] maximum
+                for field in der_items(subtree.data).skip(1) {
+                    if field.class() != Class::ContextSpecific {
+                        continue;
+                    }
+                    match field.tag().0 {
+                        0 if field.data.iter().any(|&b| b != 0) => {
+                            let details = LintDetails::from("minimum is not zero");
+                            return LintResult::new_details(LintStatus::Error, details);
+                        }
+                        1 => {
+                            let details = LintDetails::from("maximum is present");
+                            return LintResult::new_details(LintStatus::Error, details);
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_NC_INVALID_BASE,
+    "rfc:cert_ext_nc_invalid_base",
+    "Invalid base name form in NameConstraints subtree",
+    "RFC5280: 4.2.1.10"
+);
+pub(super) fn cert_ext_nc_invalid_base(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::NameConstraints(nc) = ext.parsed_extension() {
+            let subtrees = nc
+                .permitted_subtrees
+                .iter()
+                .chain(nc.excluded_subtrees.iter())
+                .flatten();
+            for subtree in subtrees {
+                if let Err(e) = check_subtree_base(&subtree.base) {
+                    let details = LintDetails::new(format!("{}: {e}", subtree.base));
+                    return LintResult::new_details(LintStatus::Error, details);
+                }
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+fn check_subtree_base(base: &GeneralName) -> Result<(), &'static str> {
+    match base {
+        GeneralName::IPAddress(b) => check_ip_constraint(b),
+        // an empty constraint matches all names
+        GeneralName::DNSName("") => Ok(()),
+        GeneralName::DNSName(s) => check_domain_constraint(s),
+        GeneralName::RFC822Name(s) if s.contains('@') => check_rfc822_name(s),
+        GeneralName::RFC822Name(s) | GeneralName::URI(s) => check_domain_constraint(s),
+        _ => Ok(()),
+    }
+}
+
+/// IP address constraint: address followed by a contiguous mask
+fn check_ip_constraint(b: &[u8]) -> Result<(), &'static str> {
+    if b.len() != 8 && b.len() != 32 {
+        return Err("iPAddress constraint MUST be 8 or 32 octets");
+    }
+    let (addr, mask) = b.split_at(b.len() / 2);
+    let bits = mask
+        .iter()
+        .flat_map(|m| (0..8).rev().map(move |i| (m >> i) & 1));
+    // skip leading ones, all remaining bits must be zero
+    if !bits.skip_while(|&bit| bit == 1).all(|bit| bit == 0) {
+        return Err("iPAddress constraint mask is not contiguous");
+    }
+    if addr.iter().zip(mask).any(|(a, m)| a & !m != 0) {
+        return Err("iPAddress constraint has bits set outside of mask");
+    }
+    Ok(())
+}
+
+/// Host or domain constraint (a leading period designates a domain)
+fn check_domain_constraint(s: &str) -> Result<(), &'static str> {
+    let name = s.strip_prefix('.').unwrap_or(s);
+    if name.contains('*') {
+        return Err("wildcard in constraint");
+    }
+    check_dns_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn name_constraints(critical: bool, subtrees: &[&[u8]]) -> Vec<u8> {
+        let permitted = der(0xa0, &subtrees.concat());
+        extension(
+            &OID_X509_EXT_NAME_CONSTRAINTS,
+            critical,
+            &seq(&[&permitted]),
+        )
+    }
+
+    #[test]
+    fn name_constraints_lints() {
+        // permitted dNSName .example.com and iPAddress 192.168.0.0/16
+        let dns = seq(&[&der(0x82, b".example.com")]);
+        let ip = seq(&[&der(0x87, &[192, 168, 0, 0, 255, 255, 0, 0])]);
+        let data = CertificateBuilder::with_extensions(vec![
+            basic_constraints(true),
+            name_constraints(true, &[&dns, &ip]),
+        ])
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(NAME_CONSTRAINTS_LINTS, &x509).is_empty());
+
+        // non-critical, in an end-entity certificate, with a wildcard and a maximum
+        let dns = seq(&[&der(0x82, b"*.example.com"), &der(0x81, &[1])]);
+        let data = CertificateBuilder::with_extensions(vec![name_constraints(false, &[&dns])]);
+        let data = data.build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(NAME_CONSTRAINTS_LINTS, &x509),
+            [
+                "rfc:cert_ext_nc_not_ca",
+                "rfc:cert_ext_nc_not_critical",
+                "rfc:cert_ext_nc_minimum_maximum",
+                "rfc:cert_ext_nc_invalid_base",
+            ]
+        );
+
+        let ext = extension(&OID_X509_EXT_NAME_CONSTRAINTS, true, &seq(&[]));
+        let data = CertificateBuilder::with_extensions(vec![basic_constraints(true), ext]).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(NAME_CONSTRAINTS_LINTS, &x509),
+            ["rfc:cert_ext_nc_empty"]
+        );
+    }
+
+    #[test]
+    fn ip_constraints() {
+        assert!(check_ip_constraint(&[10, 0, 0, 0, 255, 0, 0, 0]).is_ok());
+        assert!(check_ip_constraint(&[10, 0, 0, 0, 255, 0, 255, 0]).is_err());
+        assert!(check_ip_constraint(&[192, 168, 1, 0, 255, 255, 0, 0]).is_err());
+        assert!(check_ip_constraint(&[10, 0, 0, 0]).is_err());
+    }
+}
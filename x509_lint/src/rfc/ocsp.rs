@@ -0,0 +1,649 @@
+use sha1::{Digest, Sha1};
+use x509_parser::asn1_rs::{Any, FromDer, Tag};
+use x509_parser::extensions::X509Extension;
+use x509_parser::prelude::X509Certificate;
+use x509_parser::utils::format_serial;
+
+use super::oids::{OID_PKIX_OCSP_NOCHECK, OID_PKIX_OCSP_NONCE};
+use super::signature::HASH_LENGTHS;
+use crate::*;
+
+pub(crate) const OCSP_LINTS: &[(LintDefinition, OcspLint)] = &[
+    (OCSP_STATUS_INVALID, ocsp_status_invalid),
+    (OCSP_STATUS_UNSUCCESSFUL, ocsp_status_unsuccessful),
+    (OCSP_RESPONSE_BYTES_MISMATCH, ocsp_response_bytes_mismatch),
+    (OCSP_RESPONSE_TYPE_NOT_BASIC, ocsp_response_type_not_basic),
+    (OCSP_TIME_NOT_GENERALIZED, ocsp_time_not_generalized),
+    (
+        OCSP_THIS_UPDATE_AFTER_PRODUCED_AT,
+        ocsp_this_update_after_produced_at,
+    ),
+    (
+        OCSP_NEXT_UPDATE_BEFORE_THIS_UPDATE,
+        ocsp_next_update_before_this_update,
+    ),
+    (OCSP_NEXT_UPDATE_MISSING, ocsp_next_update_missing),
+    (OCSP_VALIDITY_TOO_SHORT, ocsp_validity_too_short),
+    (OCSP_CERT_ID_HASH_INVALID, ocsp_cert_id_hash_invalid),
+    (OCSP_RESPONDER_ID_MISMATCH, ocsp_responder_id_mismatch),
+    (OCSP_RESPONDER_EKU_MISSING, ocsp_responder_eku_missing),
+    (
+        OCSP_RESPONDER_NO_CHECK_MISSING,
+        ocsp_responder_no_check_missing,
+    ),
+    (OCSP_NONCE_INVALID, ocsp_nonce_invalid),
+    (OCSP_NONCE_IN_SINGLE_RESPONSE, ocsp_nonce_in_single_response),
+];
+
+pub(crate) const OCSP_CONFIGURABLE_LINTS: &[(LintDefinition, ConfigurableOcspLint)] =
+    &[(OCSP_VALIDITY_TOO_LONG, ocsp_validity_too_long)];
+
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+/// Minimum interval between thisUpdate and nextUpdate
+const OCSP_MIN_VALIDITY_HOURS: i64 = 8;
+
+/// Maximum length of a nonce, in octets (RFC8954)
+const NONCE_MAX_LENGTH: usize = 32;
+
+/// Run `check` on all single responses of the response
+fn check_single_responses(
+    response: &OcspResponse,
+    status: LintStatus,
+    check: impl Fn(&SingleResponse) -> Result<(), String>,
+) -> LintResult {
+    let Some(basic) = &response.basic_response else {
+        return LintResult::pass();
+    };
+    for single in &basic.tbs_response_data.responses {
+        if let Err(e) = check(single) {
+            let serial = format_serial(single.cert_id.serial_number);
+            let details = LintDetails::new(format!("{serial}: {e}"));
+            return LintResult::new_details(status, details);
+        }
+    }
+    LintResult::pass()
+}
+
+/// Return true if the certificate is identified by the ResponderID
+fn matches_responder_id(x509: &X509Certificate, responder_id: &ResponderId) -> bool {
+    match responder_id {
+        ResponderId::ByName(name) => x509.subject().as_raw() == name.as_raw(),
+        ResponderId::ByKey(hash) => {
+            let key = &x509.public_key().subject_public_key.data;
+            Sha1::digest(key).as_slice() == *hash
+        }
+    }
+}
+
+/// Return the certificate of the responder, if included in the response
+fn responder_cert<'a>(response: &'a OcspResponse) -> Option<&'a X509Certificate<'a>> {
+    let basic = response.basic_response.as_ref()?;
+    let responder_id = &basic.tbs_response_data.responder_id;
+    basic
+        .certs
+        .iter()
+        .find(|x509| matches_responder_id(x509, responder_id))
+}
+
+/// Return the certificate of the responder, if it is a delegated responder (not a CA)
+fn delegated_responder_cert<'a>(response: &'a OcspResponse) -> Option<&'a X509Certificate<'a>> {
+    responder_cert(response).filter(|x509| !x509.is_ca())
+}
+
+lint_definition!(
+    OCSP_STATUS_INVALID,
+    "rfc:ocsp_status_invalid",
+    "OCSP responseStatus MUST be one of the defined values",
+    "RFC6960: 4.2.1"
+);
+pub(super) fn ocsp_status_invalid(response: &OcspResponse) -> LintResult {
+    if response.response_status.name().is_none() {
+        let details = LintDetails::new(response.response_status.to_string());
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    OCSP_STATUS_UNSUCCESSFUL,
+    "rfc:ocsp_status_unsuccessful",
+    "OCSP response is an error response",
+    "RFC6960: 4.2.1"
+);
+pub(super) fn ocsp_status_unsuccessful(response: &OcspResponse) -> LintResult {
+    let status = response.response_status;
+    if status != OcspResponseStatus::SUCCESSFUL && status.name().is_some() {
+        return LintResult::new_details(LintStatus::Warn, LintDetails::new(status.to_string()));
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    OCSP_RESPONSE_BYTES_MISMATCH,
+    "rfc:ocsp_response_bytes_mismatch",
+    "OCSP responseBytes MUST be present in successful responses only",
+    "RFC6960: 4.2.1"
+);
+ocsp_lint!(
+    pub(super) ocsp_response_bytes_mismatch,
+    LintStatus::Error,
+    |response: &OcspResponse| (response.response_status == OcspResponseStatus::SUCCESSFUL)
+        != response.response_type.is_some()
+);
+
+lint_definition!(
+    OCSP_RESPONSE_TYPE_NOT_BASIC,
+    "rfc:ocsp_response_type_not_basic",
+    "OCSP response type is not id-pkix-ocsp-basic",
+    "RFC6960: 4.2.1"
+);
+ocsp_lint!(
+    pub(super) ocsp_response_type_not_basic,
+    LintStatus::Warn,
+    |response: &OcspResponse| response
+        .response_type
+        .as_ref()
+        .is_some_and(|oid| *oid != OID_PKIX_OCSP_BASIC)
+);
+
+lint_definition!(
+    OCSP_TIME_NOT_GENERALIZED,
+    "rfc:ocsp_time_not_generalized",
+    "OCSP producedAt, thisUpdate and nextUpdate MUST be encoded as GeneralizedTime",
+    "RFC6960: 4.2.1"
+);
+pub(super) fn ocsp_time_not_generalized(response: &OcspResponse) -> LintResult {
+    if let Some(basic) = &response.basic_response {
+        if basic.tbs_response_data.produced_at.is_utctime() {
+            return LintResult::new_details(LintStatus::Error, "producedAt".into());
+        }
+    }
+    check_single_responses(response, LintStatus::Error, |single| {
+        if single.this_update.is_utctime() {
+            return Err("thisUpdate".to_string());
+        }
+        if single.next_update.is_some_and(|t| t.is_utctime()) {
+            return Err("nextUpdate".to_string());
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    OCSP_THIS_UPDATE_AFTER_PRODUCED_AT,
+    "rfc:ocsp_this_update_after_produced_at",
+    "OCSP thisUpdate MUST NOT be later than producedAt",
+    "RFC6960: 2.4"
+);
+pub(super) fn ocsp_this_update_after_produced_at(response: &OcspResponse) -> LintResult {
+    let Some(basic) = &response.basic_response else {
+        return LintResult::pass();
+    };
+    let produced_at = basic.tbs_response_data.produced_at;
+    check_single_responses(response, LintStatus::Error, |single| {
+        if single.this_update.timestamp() > produced_at.timestamp() {
+            return Err(format!("thisUpdate {}", single.this_update));
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    OCSP_NEXT_UPDATE_BEFORE_THIS_UPDATE,
+    "rfc:ocsp_next_update_before_this_update",
+    "OCSP nextUpdate MUST be later than thisUpdate",
+    "RFC6960: 4.2.2.1"
+);
+pub(super) fn ocsp_next_update_before_this_update(response: &OcspResponse) -> LintResult {
+    check_single_responses(response, LintStatus::Error, |single| {
+        match single.next_update {
+            Some(next_update) if next_update.timestamp() <= single.this_update.timestamp() => {
+                Err(format!("nextUpdate {next_update}"))
+            }
+            _ => Ok(()),
+        }
+    })
+}
+
+lint_definition!(
+    OCSP_NEXT_UPDATE_MISSING,
+    "rfc:ocsp_next_update_missing",
+    "OCSP responses SHOULD include nextUpdate",
+    "CABF BR: 4.9.10"
+);
+pub(super) fn ocsp_next_update_missing(response: &OcspResponse) -> LintResult {
+    check_single_responses(response, LintStatus::Warn, |single| {
+        if single.next_update.is_none() {
+            return Err("no nextUpdate".to_string());
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    OCSP_VALIDITY_TOO_SHORT,
+    "rfc:ocsp_validity_too_short",
+    "Interval between OCSP thisUpdate and nextUpdate MUST be at least 8 hours",
+    "CABF BR: 4.9.10"
+);
+pub(super) fn ocsp_validity_too_short(response: &OcspResponse) -> LintResult {
+    check_single_responses(response, LintStatus::Error, |single| {
+        let Some(next_update) = single.next_update else {
+            return Ok(());
+        };
+        let seconds = next_update.timestamp() - single.this_update.timestamp();
+        // negative intervals are reported by ocsp_next_update_before_this_update
+        if seconds > 0 && seconds < OCSP_MIN_VALIDITY_HOURS * SECONDS_PER_HOUR {
+            let hours = seconds as f64 / SECONDS_PER_HOUR as f64;
+            return Err(format!("{hours:.1} hours"));
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    OCSP_VALIDITY_TOO_LONG,
+    "rfc:ocsp_validity_too_long",
+    "Interval between OCSP thisUpdate and nextUpdate is longer than the maximum",
+    "CABF BR: 4.9.10"
+);
+pub(super) fn ocsp_validity_too_long(response: &OcspResponse, config: &LintConfig) -> LintResult {
+    let max_days = config.ocsp_max_validity_days;
+    check_single_responses(response, LintStatus::Error, |single| {
+        let Some(next_update) = single.next_update else {
+            return Ok(());
+        };
+        let seconds = next_update.timestamp() - single.this_update.timestamp();
+        if seconds > i64::from(max_days) * SECONDS_PER_DAY {
+            let days = seconds as f64 / SECONDS_PER_DAY as f64;
+            return Err(format!("{days:.1} days (maximum: {max_days})"));
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    OCSP_CERT_ID_HASH_INVALID,
+    "rfc:ocsp_cert_id_hash_invalid",
+    "CertID hash algorithm is unknown, or issuer hashes do not match its output length",
+    "RFC6960: 4.1.1"
+);
+pub(super) fn ocsp_cert_id_hash_invalid(response: &OcspResponse) -> LintResult {
+    check_single_responses(response, LintStatus::Error, |single| {
+        let cert_id = &single.cert_id;
+        let alg = &cert_id.hash_algorithm.algorithm;
+        let Some(&(_, len)) = HASH_LENGTHS.iter().find(|(oid, _)| oid == alg) else {
+            return Err(format!("unknown hash algorithm {}", alg.to_id_string()));
+        };
+        for (label, hash) in [
+            ("issuerNameHash", cert_id.issuer_name_hash),
+            ("issuerKeyHash", cert_id.issuer_key_hash),
+        ] {
+            if hash.len() != len as usize {
+                return Err(format!("{label}: {} octets (expected {len})", hash.len()));
+            }
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    OCSP_RESPONDER_ID_MISMATCH,
+    "rfc:ocsp_responder_id_mismatch",
+    "OCSP ResponderID does not match any certificate included in the response",
+    "RFC6960: 4.2.2.3"
+);
+ocsp_lint!(
+    pub(super) ocsp_responder_id_mismatch,
+    LintStatus::Error,
+    |response: &OcspResponse| response
+        .basic_response
+        .as_ref()
+        .is_some_and(|basic| !basic.certs.is_empty() && responder_cert(response).is_none())
+);
+
+lint_definition!(
+    OCSP_RESPONDER_EKU_MISSING,
+    "rfc:ocsp_responder_eku_missing",
+    "Delegated OCSP responder certificate MUST include id-kp-OCSPSigning in ExtendedKeyUsage",
+    "RFC6960: 4.2.2.2"
+);
+ocsp_lint!(
+    pub(super) ocsp_responder_eku_missing,
+    LintStatus::Error,
+    |response: &OcspResponse| delegated_responder_cert(response).is_some_and(|x509| {
+        !x509
+            .extended_key_usage()
+            .ok()
+            .flatten()
+            .is_some_and(|eku| eku.value.ocsp_signing)
+    })
+);
+
+lint_definition!(
+    OCSP_RESPONDER_NO_CHECK_MISSING,
+    "rfc:ocsp_responder_no_check_missing",
+    "Delegated OCSP responder certificate MUST include the id-pkix-ocsp-nocheck extension",
+    "CABF BR: 4.9.9"
+);
+ocsp_lint!(
+    pub(super) ocsp_responder_no_check_missing,
+    LintStatus::Error,
+    |response: &OcspResponse| delegated_responder_cert(response).is_some_and(|x509| {
+        !x509
+            .extensions()
+            .iter()
+            .any(|ext| ext.oid == OID_PKIX_OCSP_NOCHECK)
+    })
+);
+
+/// Return the nonce extension from a list of extensions
+fn find_nonce<'a>(extensions: &'a [X509Extension]) -> Option<&'a X509Extension<'a>> {
+    extensions.iter().find(|ext| ext.oid == OID_PKIX_OCSP_NONCE)
+}
+
+lint_definition!(
+    OCSP_NONCE_INVALID,
+    "rfc:ocsp_nonce_invalid",
+    "OCSP nonce MUST be an OCTET STRING of 1 to 32 octets",
+    "RFC8954: 2.1"
+);
+pub(super) fn ocsp_nonce_invalid(response: &OcspResponse) -> LintResult {
+    let Some(basic) = &response.basic_response else {
+        return LintResult::pass();
+    };
+    let Some(nonce) = find_nonce(&basic.tbs_response_data.extensions) else {
+        return LintResult::pass();
+    };
+    match Any::from_der(nonce.value) {
+        Ok((rem, value)) if rem.is_empty() && value.tag() == Tag::OctetString => {
+            if value.data.is_empty() || value.data.len() > NONCE_MAX_LENGTH {
+                let details = LintDetails::new(format!("{} octets", value.data.len()));
+                return LintResult::new_details(LintStatus::Error, details);
+            }
+            LintResult::pass()
+        }
+        _ => LintResult::new_details(LintStatus::Error, "not an OCTET STRING".into()),
+    }
+}
+
+lint_definition!(
+    OCSP_NONCE_IN_SINGLE_RESPONSE,
+    "rfc:ocsp_nonce_in_single_response",
+    "OCSP nonce SHOULD be included in responseExtensions, not singleExtensions",
+    "RFC6960: 4.4.1"
+);
+pub(super) fn ocsp_nonce_in_single_response(response: &OcspResponse) -> LintResult {
+    check_single_responses(response, LintStatus::Warn, |single| {
+        if find_nonce(&single.extensions).is_some() {
+            return Err("nonce in singleExtensions".to_string());
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::{OID_NIST_HASH_SHA256, OID_X509_EXT_EXTENDED_KEY_USAGE};
+
+    use super::*;
+    use crate::rfc::oids::OID_KP_OCSP_SIGNING;
+    use crate::rfc::testing::*;
+
+    fn failures(builder: &OcspResponseBuilder) -> Vec<String> {
+        let data = builder.build();
+        let (_, response) = OcspResponse::from_der(&data).unwrap();
+        ocsp_lint_failures(OCSP_LINTS, &response)
+    }
+
+    /// Certificate of a delegated OCSP responder
+    fn responder_cert(extensions: Vec<Vec<u8>>) -> CertificateBuilder {
+        CertificateBuilder {
+            subject: name("OCSP Responder"),
+            extensions,
+            ..Default::default()
+        }
+    }
+
+    fn ocsp_signing() -> Vec<u8> {
+        extension(
+            &OID_X509_EXT_EXTENDED_KEY_USAGE,
+            false,
+            &seq(&[&oid(&OID_KP_OCSP_SIGNING)]),
+        )
+    }
+
+    fn ocsp_no_check() -> Vec<u8> {
+        extension(&OID_PKIX_OCSP_NOCHECK, false, &der(0x05, &[]))
+    }
+
+    fn nonce(value: &[u8]) -> Vec<u8> {
+        extension(&OID_PKIX_OCSP_NONCE, false, value)
+    }
+
+    #[test]
+    fn ocsp_response_asset() {
+        let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("assets/ocsp_response.der");
+        let data = std::fs::read(path).expect("DER file not found");
+        let (_, response) = OcspResponse::from_der(&data).unwrap();
+        assert!(ocsp_lint_failures(OCSP_LINTS, &response).is_empty());
+    }
+
+    #[test]
+    fn response_status() {
+        let invalid = OcspResponseBuilder {
+            response_status: 7,
+            response_type: None,
+            ..Default::default()
+        };
+        assert_eq!(failures(&invalid), ["rfc:ocsp_status_invalid"]);
+        let unsuccessful = OcspResponseBuilder {
+            response_status: 3,
+            response_type: None,
+            ..Default::default()
+        };
+        assert_eq!(failures(&unsuccessful), ["rfc:ocsp_status_unsuccessful"]);
+        let no_bytes = OcspResponseBuilder {
+            response_type: None,
+            ..Default::default()
+        };
+        assert_eq!(failures(&no_bytes), ["rfc:ocsp_response_bytes_mismatch"]);
+        let unsuccessful_with_bytes = OcspResponseBuilder {
+            response_status: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&unsuccessful_with_bytes),
+            [
+                "rfc:ocsp_status_unsuccessful",
+                "rfc:ocsp_response_bytes_mismatch",
+            ]
+        );
+        let not_basic = OcspResponseBuilder {
+            response_type: Some("1.2.3.4".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(failures(&not_basic), ["rfc:ocsp_response_type_not_basic"]);
+    }
+
+    #[test]
+    fn time_encoding() {
+        let produced_at = OcspResponseBuilder {
+            produced_at: der(0x17, b"250101000000Z"),
+            ..Default::default()
+        };
+        assert_eq!(failures(&produced_at), ["rfc:ocsp_time_not_generalized"]);
+        let this_update = OcspResponseBuilder {
+            this_update: der(0x17, b"250101000000Z"),
+            ..Default::default()
+        };
+        assert_eq!(failures(&this_update), ["rfc:ocsp_time_not_generalized"]);
+    }
+
+    #[test]
+    fn validity_lints() {
+        let after_produced_at = OcspResponseBuilder {
+            this_update: der(0x18, b"20250101120000Z"),
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&after_produced_at),
+            ["rfc:ocsp_this_update_after_produced_at"]
+        );
+        let next_update_before = OcspResponseBuilder {
+            next_update: Some(der(0x18, b"20241231000000Z")),
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&next_update_before),
+            ["rfc:ocsp_next_update_before_this_update"]
+        );
+        let no_next_update = OcspResponseBuilder {
+            next_update: None,
+            ..Default::default()
+        };
+        assert_eq!(failures(&no_next_update), ["rfc:ocsp_next_update_missing"]);
+        let too_short = OcspResponseBuilder {
+            next_update: Some(der(0x18, b"20250101040000Z")),
+            ..Default::default()
+        };
+        assert_eq!(failures(&too_short), ["rfc:ocsp_validity_too_short"]);
+    }
+
+    #[test]
+    fn validity_too_long() {
+        let data = OcspResponseBuilder {
+            next_update: Some(der(0x18, b"20250201000000Z")),
+            ..Default::default()
+        }
+        .build();
+        let (_, response) = OcspResponse::from_der(&data).unwrap();
+        let config = LintConfig::default();
+        assert_eq!(
+            ocsp_validity_too_long(&response, &config).status,
+            LintStatus::Error
+        );
+        let config = LintConfig {
+            ocsp_max_validity_days: 31,
+            ..Default::default()
+        };
+        assert_eq!(
+            ocsp_validity_too_long(&response, &config).status,
+            LintStatus::Pass
+        );
+    }
+
+    #[test]
+    fn cert_id_hash() {
+        let length_mismatch = OcspResponseBuilder {
+            hash_algorithm: seq(&[&oid(&OID_NIST_HASH_SHA256), &der(0x05, &[])]),
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&length_mismatch),
+            ["rfc:ocsp_cert_id_hash_invalid"]
+        );
+        let unknown = OcspResponseBuilder {
+            hash_algorithm: seq(&[&oid(&"1.2.3.4".parse().unwrap())]),
+            ..Default::default()
+        };
+        assert_eq!(failures(&unknown), ["rfc:ocsp_cert_id_hash_invalid"]);
+    }
+
+    #[test]
+    fn responder_id() {
+        let delegated = responder_cert(vec![ocsp_signing(), ocsp_no_check()]).build();
+        let (_, x509) = X509Certificate::from_der(&delegated).unwrap();
+        let key_hash = Sha1::digest(&x509.public_key().subject_public_key.data);
+
+        let by_name = OcspResponseBuilder {
+            responder_id: der(0xa1, &name("OCSP Responder")),
+            certs: vec![delegated.clone()],
+            ..Default::default()
+        };
+        assert!(failures(&by_name).is_empty());
+        let by_key = OcspResponseBuilder {
+            responder_id: der(0xa2, &octets(&key_hash)),
+            certs: vec![delegated.clone()],
+            ..Default::default()
+        };
+        assert!(failures(&by_key).is_empty());
+
+        let other_name = OcspResponseBuilder {
+            responder_id: der(0xa1, &name("Other Responder")),
+            certs: vec![delegated.clone()],
+            ..Default::default()
+        };
+        assert_eq!(failures(&other_name), ["rfc:ocsp_responder_id_mismatch"]);
+        let other_key = OcspResponseBuilder {
+            responder_id: der(0xa2, &octets(&[0; 20])),
+            certs: vec![delegated],
+            ..Default::default()
+        };
+        assert_eq!(failures(&other_key), ["rfc:ocsp_responder_id_mismatch"]);
+    }
+
+    #[test]
+    fn delegated_responder() {
+        let responder_id = der(0xa1, &name("OCSP Responder"));
+        let missing_extensions = OcspResponseBuilder {
+            responder_id: responder_id.clone(),
+            certs: vec![responder_cert(Vec::new()).build()],
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&missing_extensions),
+            [
+                "rfc:ocsp_responder_eku_missing",
+                "rfc:ocsp_responder_no_check_missing",
+            ]
+        );
+
+        let missing_no_check = OcspResponseBuilder {
+            responder_id: responder_id.clone(),
+            certs: vec![responder_cert(vec![ocsp_signing()]).build()],
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&missing_no_check),
+            ["rfc:ocsp_responder_no_check_missing"]
+        );
+
+        // responses signed by the CA do not need a delegated responder certificate
+        let ca = OcspResponseBuilder {
+            responder_id,
+            certs: vec![responder_cert(vec![basic_constraints(true)]).build()],
+            ..Default::default()
+        };
+        assert!(failures(&ca).is_empty());
+    }
+
+    #[test]
+    fn nonce_lints() {
+        let valid = OcspResponseBuilder {
+            extensions: vec![nonce(&octets(&[0x5a; 16]))],
+            ..Default::default()
+        };
+        assert!(failures(&valid).is_empty());
+
+        for value in [octets(&[]), octets(&[0x5a; 33]), int(&[1])] {
+            let invalid = OcspResponseBuilder {
+                extensions: vec![nonce(&value)],
+                ..Default::default()
+            };
+            assert_eq!(failures(&invalid), ["rfc:ocsp_nonce_invalid"]);
+        }
+
+        let in_single_response = OcspResponseBuilder {
+            single_extensions: vec![nonce(&octets(&[0x5a; 16]))],
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&in_single_response),
+            ["rfc:ocsp_nonce_in_single_response"]
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Object identifiers not provided by `oid_registry`
+//!
+//! This module is not formatted by rustfmt, which breaks the `oid!` macro arguments.
+
+use x509_parser::asn1_rs::{oid, Oid};
+
+/// Special policy identifier `anyPolicy`
+pub(super) const OID_ANY_POLICY: Oid<'static> = oid!(2.5.29.32.0);
+/// Policy qualifier `id-qt-cps`
+pub(super) const OID_QT_CPS: Oid<'static> = oid!(1.3.6.1.5.5.7.2.1);
+/// Policy qualifier `id-qt-unotice`
+pub(super) const OID_QT_UNOTICE: Oid<'static> = oid!(1.3.6.1.5.5.7.2.2);
+
+/// Extension `inhibitAnyPolicy` (the name in `oid_registry` depends on the version)
+pub(super) const OID_X509_EXT_INHIBIT_ANY_POLICY: Oid<'static> = oid!(2.5.29.54);
+/// Extension `subjectInfoAccess` (not available in all versions of `oid_registry`)
+pub(super) const OID_PKIX_SUBJECT_INFO_ACCESS: Oid<'static> = oid!(1.3.6.1.5.5.7.1.11);
+
+/// Attribute type `pseudonym`
+pub(super) const OID_X509_PSEUDONYM: Oid<'static> = oid!(2.5.4.65);
+
+/// Key agreement algorithm `id-X25519` (RFC8410)
+pub(super) const OID_KEY_TYPE_X25519: Oid<'static> = oid!(1.3.101.110);
+/// Key agreement algorithm `id-X448` (RFC8410)
+pub(super) const OID_KEY_TYPE_X448: Oid<'static> = oid!(1.3.101.111);
+
+/// Curve `secp192r1` (P-192)
+pub(super) const OID_EC_P192: Oid<'static> = oid!(1.2.840.10045.3.1.1);
+/// Curve `secp224r1` (P-224)
+pub(super) const OID_EC_P224: Oid<'static> = oid!(1.3.132.0.33);
+/// Curve `secp256k1`
+pub(super) const OID_EC_SECP256K1: Oid<'static> = oid!(1.3.132.0.10);
+/// Curve `brainpoolP256r1` (RFC5639)
+pub(super) const OID_EC_BRAINPOOL_P256R1: Oid<'static> = oid!(1.3.36.3.3.2.8.1.1.7);
+/// Curve `brainpoolP384r1` (RFC5639)
+pub(super) const OID_EC_BRAINPOOL_P384R1: Oid<'static> = oid!(1.3.36.3.3.2.8.1.1.11);
+/// Curve `brainpoolP512r1` (RFC5639)
+pub(super) const OID_EC_BRAINPOOL_P512R1: Oid<'static> = oid!(1.3.36.3.3.2.8.1.1.13);
+
+/// Signature algorithm `ecdsa-with-SHA1` (RFC3279)
+pub(super) const OID_SIG_ECDSA_WITH_SHA1: Oid<'static> = oid!(1.2.840.10045.4.1);
+/// Mask generation function `id-mgf1` (RFC4055)
+pub(super) const OID_PKCS1_MGF1: Oid<'static> = oid!(1.2.840.113549.1.1.8);
+/// Hash algorithm `id-sha224` (RFC4055)
+pub(super) const OID_NIST_HASH_SHA224: Oid<'static> = oid!(2.16.840.1.101.3.4.2.4);
+
+/// Signature algorithm `id-dsa-with-sha224` (RFC5758)
+pub(super) const OID_SIG_DSA_WITH_SHA224: Oid<'static> = oid!(2.16.840.1.101.3.4.3.1);
+/// Signature algorithm `id-dsa-with-sha256` (RFC5758)
+pub(super) const OID_SIG_DSA_WITH_SHA256: Oid<'static> = oid!(2.16.840.1.101.3.4.3.2);
+
+/// Signature algorithms `id-ml-dsa-*` (FIPS 204)
+pub(super) const OID_ML_DSA_44: Oid<'static> = oid!(2.16.840.1.101.3.4.3.17);
+pub(super) const OID_ML_DSA_65: Oid<'static> = oid!(2.16.840.1.101.3.4.3.18);
+pub(super) const OID_ML_DSA_87: Oid<'static> = oid!(2.16.840.1.101.3.4.3.19);
+/// Signature algorithms `id-slh-dsa-*` (FIPS 205)
+pub(super) const OID_SLH_DSA_SHA2_128S: Oid<'static> = oid!(2.16.840.1.101.3.4.3.20);
+pub(super) const OID_SLH_DSA_SHA2_128F: Oid<'static> = oid!(2.16.840.1.101.3.4.3.21);
+pub(super) const OID_SLH_DSA_SHA2_192S: Oid<'static> = oid!(2.16.840.1.101.3.4.3.22);
+pub(super) const OID_SLH_DSA_SHA2_192F: Oid<'static> = oid!(2.16.840.1.101.3.4.3.23);
+pub(super) const OID_SLH_DSA_SHA2_256S: Oid<'static> = oid!(2.16.840.1.101.3.4.3.24);
+pub(super) const OID_SLH_DSA_SHA2_256F: Oid<'static> = oid!(2.16.840.1.101.3.4.3.25);
+pub(super) const OID_SLH_DSA_SHAKE_128S: Oid<'static> = oid!(2.16.840.1.101.3.4.3.26);
+pub(super) const OID_SLH_DSA_SHAKE_128F: Oid<'static> = oid!(2.16.840.1.101.3.4.3.27);
+pub(super) const OID_SLH_DSA_SHAKE_192S: Oid<'static> = oid!(2.16.840.1.101.3.4.3.28);
+pub(super) const OID_SLH_DSA_SHAKE_192F: Oid<'static> = oid!(2.16.840.1.101.3.4.3.29);
+pub(super) const OID_SLH_DSA_SHAKE_256S: Oid<'static> = oid!(2.16.840.1.101.3.4.3.30);
+pub(super) const OID_SLH_DSA_SHAKE_256F: Oid<'static> = oid!(2.16.840.1.101.3.4.3.31);
+/// Key encapsulation mechanisms `id-alg-ml-kem-*` (FIPS 203)
+pub(super) const OID_ML_KEM_512: Oid<'static> = oid!(2.16.840.1.101.3.4.4.1);
+pub(super) const OID_ML_KEM_768: Oid<'static> = oid!(2.16.840.1.101.3.4.4.2);
+pub(super) const OID_ML_KEM_1024: Oid<'static> = oid!(2.16.840.1.101.3.4.4.3);
+/// Composite ML-DSA signature algorithms (draft-ietf-lamps-pq-composite-sigs)
+pub(super) const OID_MLDSA44_RSA2048_PSS_SHA256: Oid<'static> = oid!(1.3.6.1.5.5.7.6.37);
+pub(super) const OID_MLDSA44_RSA2048_PKCS15_SHA256: Oid<'static> = oid!(1.3.6.1.5.5.7.6.38);
+pub(super) const OID_MLDSA44_ED25519_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.39);
+pub(super) const OID_MLDSA44_ECDSA_P256_SHA256: Oid<'static> = oid!(1.3.6.1.5.5.7.6.40);
+pub(super) const OID_MLDSA65_RSA3072_PSS_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.41);
+pub(super) const OID_MLDSA65_RSA3072_PKCS15_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.42);
+pub(super) const OID_MLDSA65_RSA4096_PSS_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.43);
+pub(super) const OID_MLDSA65_RSA4096_PKCS15_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.44);
+pub(super) const OID_MLDSA65_ECDSA_P256_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.45);
+pub(super) const OID_MLDSA65_ECDSA_P384_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.46);
+pub(super) const OID_MLDSA65_ECDSA_BRAINPOOL_P256R1_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.47);
+pub(super) const OID_MLDSA65_ED25519_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.48);
+pub(super) const OID_MLDSA87_ECDSA_P384_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.49);
+pub(super) const OID_MLDSA87_ECDSA_BRAINPOOL_P384R1_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.50);
+pub(super) const OID_MLDSA87_ED448_SHAKE256: Oid<'static> = oid!(1.3.6.1.5.5.7.6.51);
+pub(super) const OID_MLDSA87_RSA3072_PSS_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.52);
+pub(super) const OID_MLDSA87_RSA4096_PSS_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.53);
+pub(super) const OID_MLDSA87_ECDSA_P521_SHA512: Oid<'static> = oid!(1.3.6.1.5.5.7.6.54);
+
+/// OCSP response extension `id-pkix-ocsp-nonce` (RFC8954)
+pub(super) const OID_PKIX_OCSP_NONCE: Oid<'static> = oid!(1.3.6.1.5.5.7.48.1.2);
+/// Certificate extension `id-pkix-ocsp-nocheck` (RFC6960)
+pub(super) const OID_PKIX_OCSP_NOCHECK: Oid<'static> = oid!(1.3.6.1.5.5.7.48.1.5);
+
+// Extended key usages (parsed by `x509_parser`, only used to build tests)
+#[cfg(test)]
+pub(super) const OID_KP_ANY_EXTENDED_KEY_USAGE: Oid<'static> = oid!(2.5.29.37.0);
+#[cfg(test)]
+pub(super) const OID_KP_SERVER_AUTH: Oid<'static> = oid!(1.3.6.1.5.5.7.3.1);
+#[cfg(test)]
+pub(super) const OID_KP_CLIENT_AUTH: Oid<'static> = oid!(1.3.6.1.5.5.7.3.2);
+#[cfg(test)]
+pub(super) const OID_KP_OCSP_SIGNING: Oid<'static> = oid!(1.3.6.1.5.5.7.3.9);
@@ -0,0 +1,538 @@
+use x509_parser::asn1_rs::{Any, FromDer, Oid, Tag};
+use x509_parser::extensions::*;
+use x509_parser::oid_registry::{OID_X509_EXT_POLICY_CONSTRAINTS, OID_X509_EXT_POLICY_MAPPINGS};
+use x509_parser::prelude::X509Certificate;
+
+use super::helpers::{check_uri, der_items};
+use super::oids::*;
+use crate::*;
+
+pub(crate) const POLICIES_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_CP_DUPLICATE, cert_ext_cp_duplicate),
+    (CERT_EXT_CP_QUALIFIER_UNKNOWN, cert_ext_cp_qualifier_unknown),
+    (
+        CERT_EXT_CP_EXPLICIT_TEXT_TOO_LONG,
+        cert_ext_cp_explicit_text_too_long,
+    ),
+    (
+        CERT_EXT_CP_EXPLICIT_TEXT_ENCODING,
+        cert_ext_cp_explicit_text_encoding,
+    ),
+    (CERT_EXT_CP_CPS_URI_INVALID, cert_ext_cp_cps_uri_invalid),
+    (CERT_EXT_CP_NOTICE_REF, cert_ext_cp_notice_ref),
+    (
+        CERT_EXT_CP_ANYPOLICY_WITH_OTHERS,
+        cert_ext_cp_anypolicy_with_others,
+    ),
+    (CERT_EXT_PM_NOT_CRITICAL, cert_ext_pm_not_critical),
+    (CERT_EXT_PM_ANYPOLICY, cert_ext_pm_anypolicy),
+    (CERT_EXT_PM_NOT_CA, cert_ext_pm_not_ca),
+    (CERT_EXT_PC_NOT_CRITICAL, cert_ext_pc_not_critical),
+    (CERT_EXT_PC_EMPTY, cert_ext_pc_empty),
+    (CERT_EXT_IAP_NOT_CRITICAL, cert_ext_iap_not_critical),
+    (CERT_EXT_IAP_NOT_CA, cert_ext_iap_not_ca),
+];
+
+/// Iterate on all the policies of all CertificatePolicies extensions
+fn policies<'a>(x509: &'a X509Certificate) -> impl Iterator<Item = &'a PolicyInformation<'a>> {
+    x509.extensions()
+        .iter()
+        .filter_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::CertificatePolicies(policies) => Some(policies.iter()),
+            _ => None,
+        })
+        .flatten()
+}
+
+/// Iterate on all qualifiers with the given qualifier identifier, returning the parsed qualifier
+fn qualifiers<'a>(
+    x509: &'a X509Certificate,
+    qualifier_id: Oid<'static>,
+) -> impl Iterator<Item = Any<'a>> {
+    policies(x509)
+        .flat_map(|policy| policy.policy_qualifiers.iter().flatten())
+        .filter(move |q| q.policy_qualifier_id == qualifier_id)
+        .filter_map(|q| Any::from_der(q.qualifier).ok().map(|(_, any)| any))
+}
+
+/// Iterate on the explicitText of all UserNotice qualifiers
+fn explicit_texts<'a>(x509: &'a X509Certificate) -> impl Iterator<Item = Any<'a>> {
+    qualifiers(x509, OID_QT_UNOTICE)
+        .flat_map(|notice| der_items(notice.data))
+        .filter(|item| item.tag() != Tag::Sequence)
+}
+
+lint_definition!(
+    CERT_EXT_CP_DUPLICATE,
+    "rfc:cert_ext_cp_duplicate",
+    "A certificate policy OID MUST NOT appear more than once in a certificate policies extension",
+    "RFC5280: 4.2.1.4"
+);
+pub(super) fn cert_ext_cp_duplicate(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::CertificatePolicies(policies) = ext.parsed_extension() {
+            for (idx, policy) in policies.iter().enumerate() {
+                if policies[..idx]
+                    .iter()
+                    .any(|p| p.policy_id == policy.policy_id)
+                {
+                    let details = LintDetails::new(policy.policy_id.to_id_string());
+                    return LintResult::new_details(LintStatus::Error, details);
+                }
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_CP_QUALIFIER_UNKNOWN,
+    "rfc:cert_ext_cp_qualifier_unknown",
+    "Policy qualifiers should be limited to CPS pointer and user notice (MUST for anyPolicy)",
+    "RFC5280: 4.2.1.4"
+);
+pub(super) fn cert_ext_cp_qualifier_unknown(x509: &X509Certificate) -> LintResult {
+    let mut result = LintResult::pass();
+    for policy in policies(x509) {
+        for qualifier in policy.policy_qualifiers.iter().flatten() {
+            let id = &qualifier.policy_qualifier_id;
+            if *id == OID_QT_CPS || *id == OID_QT_UNOTICE {
+                continue;
+            }
+            let details = LintDetails::new(format!(
+                "qualifier {} in policy {}",
+                id.to_id_string(),
+                policy.policy_id.to_id_string()
+            ));
+            if policy.policy_id == OID_ANY_POLICY {
+                return LintResult::new_details(LintStatus::Error, details);
+            }
+            if result.status == LintStatus::Pass {
+                result = LintResult::new_details(LintStatus::Warn, details);
+            }
+        }
+    }
+    result
+}
+
+lint_definition!(
+    CERT_EXT_CP_EXPLICIT_TEXT_TOO_LONG,
+    "rfc:cert_ext_cp_explicit_text_too_long",
+    "UserNotice explicitText MUST NOT be longer than 200 characters",
+    "RFC5280: 4.2.1.4"
+);
+pub(super) fn cert_ext_cp_explicit_text_too_long(x509: &X509Certificate) -> LintResult {
+    for text in explicit_texts(x509) {
+        let len = match text.tag() {
+            Tag::BmpString => text.data.len() / 2,
+            Tag::Utf8String => String::from_utf8_lossy(text.data).chars().count(),
+            _ => text.data.len(),
+        };
+        if len > 200 {
+            let details = LintDetails::new(format!("{len} characters"));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_CP_EXPLICIT_TEXT_ENCODING,
+    "rfc:cert_ext_cp_explicit_text_encoding",
+    "UserNotice explicitText should be encoded as UTF8String or VisibleString (MUST NOT be IA5String)",
+    "RFC6818: 3"
+);
+pub(super) fn cert_ext_cp_explicit_text_encoding(x509: &X509Certificate) -> LintResult {
+    let mut result = LintResult::pass();
+    for text in explicit_texts(x509) {
+        match text.tag() {
+            Tag::Utf8String | Tag::VisibleString => (),
+            Tag::Ia5String => {
+                return LintResult::new_details(LintStatus::Error, "IA5String".into());
+            }
+            tag => {
+                let details = LintDetails::new(format!("{tag}"));
+                result = LintResult::new_details(LintStatus::Warn, details);
+            }
+        }
+    }
+    result
+}
+
+lint_definition!(
+    CERT_EXT_CP_CPS_URI_INVALID,
+    "rfc:cert_ext_cp_cps_uri_invalid",
+    "CPS pointer qualifier MUST be an IA5String containing a valid URI",
+    "RFC5280: 4.2.1.4"
+);
+pub(super) fn cert_ext_cp_cps_uri_invalid(x509: &X509Certificate) -> LintResult {
+    for cps in qualifiers(x509, OID_QT_CPS) {
+        if cps.tag() != Tag::Ia5String {
+            let details = LintDetails::new(format!("CPS URI encoded as {}", cps.tag()));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+        let uri = String::from_utf8_lossy(cps.data);
+        if let Err(e) = check_uri(&uri) {
+            let details = LintDetails::new(format!("'{uri}': {e}"));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_CP_NOTICE_REF,
+    "rfc:cert_ext_cp_notice_ref",
+    "Conforming CAs SHOULD NOT use the noticeRef option",
+    "RFC5280: 4.2.1.4"
+);
+certificate_lint!(
+    pub(super) cert_ext_cp_notice_ref,
+    LintStatus::Warn,
+    |x509: &X509Certificate| qualifiers(x509, OID_QT_UNOTICE)
+        .flat_map(|notice| der_items(notice.data))
+        .any(|item| item.tag() == Tag::Sequence)
+);
+
+lint_definition!(
+    CERT_EXT_CP_ANYPOLICY_WITH_OTHERS,
+    "rfc:cert_ext_cp_anypolicy_with_others",
+    "Certificate policies contain anyPolicy combined with specific policies"
+);
+certificate_lint!(
+    pub(super) cert_ext_cp_anypolicy_with_others,
+    LintStatus::Warn,
+    |x509: &X509Certificate| {
+        let (any, specific): (Vec<_>, Vec<_>) =
+            policies(x509).partition(|p| p.policy_id == OID_ANY_POLICY);
+        !any.is_empty() && !specific.is_empty()
+    }
+);
+
+lint_definition!(
+    CERT_EXT_PM_NOT_CRITICAL,
+    "rfc:cert_ext_pm_not_critical",
+    "Conforming CAs SHOULD mark the PolicyMappings extension as critical",
+    "RFC5280: 4.2.1.5"
+);
+certificate_lint!(
+    pub(super) cert_ext_pm_not_critical,
+    LintStatus::Warn,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_POLICY_MAPPINGS && !ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_PM_ANYPOLICY,
+    "rfc:cert_ext_pm_anypolicy",
+    "Policies MUST NOT be mapped either to or from the special value anyPolicy",
+    "RFC5280: 4.2.1.5"
+);
+pub(super) fn cert_ext_pm_anypolicy(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::PolicyMappings(pm) = ext.parsed_extension() {
+            for mapping in &pm.mappings {
+                if mapping.issuer_domain_policy == OID_ANY_POLICY
+                    || mapping.subject_domain_policy == OID_ANY_POLICY
+                {
+                    let details = LintDetails::new(format!(
+                        "{} -> {}",
+                        mapping.issuer_domain_policy.to_id_string(),
+                        mapping.subject_domain_policy.to_id_string()
+                    ));
+                    return LintResult::new_details(LintStatus::Error, details);
+                }
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_PM_NOT_CA,
+    "rfc:cert_ext_pm_not_ca",
+    "The PolicyMappings extension should only be used in CA certificates",
+    "RFC5280: 4.2.1.5"
+);
+certificate_lint!(
+    pub(super) cert_ext_pm_not_ca,
+    LintStatus::Warn,
+    |x509: &X509Certificate| !x509.is_ca()
+        && x509
+            .iter_extensions()
+            .any(|ext| ext.oid == OID_X509_EXT_POLICY_MAPPINGS)
+);
+
+lint_definition!(
+    CERT_EXT_PC_NOT_CRITICAL,
+    "rfc:cert_ext_pc_not_critical",
+    "Conforming CAs MUST mark the PolicyConstraints extension as critical",
+    "RFC5280: 4.2.1.11"
+);
+certificate_lint!(
+    pub(super) cert_ext_pc_not_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_POLICY_CONSTRAINTS && !ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_PC_EMPTY,
+    "rfc:cert_ext_pc_empty",
+    "Conforming CAs MUST NOT issue certificates where policy constraints is an empty sequence",
+    "RFC5280: 4.2.1.11"
+);
+pub(super) fn cert_ext_pc_empty(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::PolicyConstraints(pc) = ext.parsed_extension() {
+            if pc.require_explicit_policy.is_none() && pc.inhibit_policy_mapping.is_none() {
+                return LintResult::new(LintStatus::Error);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_IAP_NOT_CRITICAL,
+    "rfc:cert_ext_iap_not_critical",
+    "Conforming CAs MUST mark the InhibitAnyPolicy extension as critical",
+    "RFC5280: 4.2.1.14"
+);
+certificate_lint!(
+    pub(super) cert_ext_iap_not_critical,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509
+        .iter_extensions()
+        .any(|ext| ext.oid == OID_X509_EXT_INHIBIT_ANY_POLICY && !ext.critical)
+);
+
+lint_definition!(
+    CERT_EXT_IAP_NOT_CA,
+    "rfc:cert_ext_iap_not_ca",
+    "The InhibitAnyPolicy extension should only be used in CA certificates",
+    "RFC5280: 4.2.1.14"
+);
+certificate_lint!(
+    pub(super) cert_ext_iap_not_ca,
+    LintStatus::Warn,
+    |x509: &X509Certificate| !x509.is_ca()
+        && x509
+            .iter_extensions()
+            .any(|ext| ext.oid == OID_X509_EXT_INHIBIT_ANY_POLICY)
+);
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::OID_X509_EXT_CERTIFICATE_POLICIES;
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    /// CA/Browser Forum domain-validated policy
+    fn dv_policy() -> Oid<'static> {
+        "2.23.140.1.2.1".parse().unwrap()
+    }
+
+    fn policy(policy_id: &Oid, qualifiers: &[Vec<u8>]) -> Vec<u8> {
+        if qualifiers.is_empty() {
+            return seq(&[&oid(policy_id)]);
+        }
+        seq(&[&oid(policy_id), &der(0x30, &qualifiers.concat())])
+    }
+
+    fn qualifier(qualifier_id: &Oid, value: &[u8]) -> Vec<u8> {
+        seq(&[&oid(qualifier_id), value])
+    }
+
+    fn cps(uri: &[u8]) -> Vec<u8> {
+        qualifier(&OID_QT_CPS, &der(0x16, uri))
+    }
+
+    fn user_notice(explicit_text: &[u8]) -> Vec<u8> {
+        qualifier(&OID_QT_UNOTICE, &seq(&[explicit_text]))
+    }
+
+    /// Certificate with a CertificatePolicies extension
+    fn policies_certificate(policies: &[Vec<u8>]) -> Vec<u8> {
+        let ext = extension(
+            &OID_X509_EXT_CERTIFICATE_POLICIES,
+            false,
+            &der(0x30, &policies.concat()),
+        );
+        CertificateBuilder::with_extensions(vec![ext]).build()
+    }
+
+    fn failures(policies: &[Vec<u8>]) -> Vec<String> {
+        let data = policies_certificate(policies);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        cert_lint_failures(POLICIES_LINTS, &x509)
+    }
+
+    #[test]
+    fn certificate_policies() {
+        let qualifiers = [
+            cps(b"http://www.example.com/cps"),
+            user_notice(&der(0x0c, b"Test policy")),
+        ];
+        assert!(failures(&[policy(&dv_policy(), &qualifiers)]).is_empty());
+
+        let duplicate = [policy(&dv_policy(), &[]), policy(&dv_policy(), &[])];
+        assert_eq!(failures(&duplicate), ["rfc:cert_ext_cp_duplicate"]);
+        let with_any_policy = [policy(&dv_policy(), &[]), policy(&OID_ANY_POLICY, &[])];
+        assert_eq!(
+            failures(&with_any_policy),
+            ["rfc:cert_ext_cp_anypolicy_with_others"]
+        );
+    }
+
+    #[test]
+    fn unknown_qualifiers() {
+        let private_oid: Oid<'static> = "1.3.6.1.4.1.311.21.10".parse().unwrap();
+        let unknown = [qualifier(&private_oid, &der(0x0c, b"private"))];
+        let data = policies_certificate(&[policy(&dv_policy(), &unknown)]);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_ext_cp_qualifier_unknown(&x509).status,
+            LintStatus::Warn
+        );
+
+        // anyPolicy MUST only use the CPS pointer and user notice qualifiers
+        let data = policies_certificate(&[policy(&OID_ANY_POLICY, &unknown)]);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_ext_cp_qualifier_unknown(&x509).status,
+            LintStatus::Error
+        );
+    }
+
+    #[test]
+    fn cps_uri() {
+        let invalid_uri = [cps(b"www.example.com/cps")];
+        assert_eq!(
+            failures(&[policy(&dv_policy(), &invalid_uri)]),
+            ["rfc:cert_ext_cp_cps_uri_invalid"]
+        );
+        let utf8_uri = [qualifier(
+            &OID_QT_CPS,
+            &der(0x0c, b"http://www.example.com/cps"),
+        )];
+        assert_eq!(
+            failures(&[policy(&dv_policy(), &utf8_uri)]),
+            ["rfc:cert_ext_cp_cps_uri_invalid"]
+        );
+    }
+
+    #[test]
+    fn explicit_text() {
+        let text_200 = [user_notice(&der(0x0c, &[b'a'; 200]))];
+        assert!(failures(&[policy(&dv_policy(), &text_200)]).is_empty());
+        let text_201 = [user_notice(&der(0x0c, &[b'a'; 201]))];
+        assert_eq!(
+            failures(&[policy(&dv_policy(), &text_201)]),
+            ["rfc:cert_ext_cp_explicit_text_too_long"]
+        );
+        // BMPString: 2 octets per character
+        let bmp_text_200 = [user_notice(&der(0x1e, &[0x00, b'a'].repeat(200)))];
+        let data = policies_certificate(&[policy(&dv_policy(), &bmp_text_200)]);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_ext_cp_explicit_text_too_long(&x509).status,
+            LintStatus::Pass
+        );
+        assert_eq!(
+            cert_ext_cp_explicit_text_encoding(&x509).status,
+            LintStatus::Warn
+        );
+
+        let ia5_text = [user_notice(&der(0x16, b"Test policy"))];
+        assert_eq!(
+            failures(&[policy(&dv_policy(), &ia5_text)]),
+            ["rfc:cert_ext_cp_explicit_text_encoding"]
+        );
+
+        let notice_ref = seq(&[&der(0x0c, b"Example"), &seq(&[&int(&[1])])]);
+        let with_notice_ref = [qualifier(
+            &OID_QT_UNOTICE,
+            &seq(&[&notice_ref, &der(0x0c, b"Test policy")]),
+        )];
+        assert_eq!(
+            failures(&[policy(&dv_policy(), &with_notice_ref)]),
+            ["rfc:cert_ext_cp_notice_ref"]
+        );
+    }
+
+    fn extension_failures(extensions: Vec<Vec<u8>>) -> Vec<String> {
+        let data = CertificateBuilder::with_extensions(extensions).build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        cert_lint_failures(POLICIES_LINTS, &x509)
+    }
+
+    fn policy_mappings(critical: bool, issuer_policy: &Oid, subject_policy: &Oid) -> Vec<u8> {
+        let mapping = seq(&[&oid(issuer_policy), &oid(subject_policy)]);
+        extension(&OID_X509_EXT_POLICY_MAPPINGS, critical, &seq(&[&mapping]))
+    }
+
+    #[test]
+    fn policy_mappings_lints() {
+        let ov_policy: Oid<'static> = "2.23.140.1.2.2".parse().unwrap();
+        let mapping = |critical| policy_mappings(critical, &dv_policy(), &ov_policy);
+        assert!(extension_failures(vec![basic_constraints(true), mapping(true)]).is_empty());
+        assert_eq!(
+            extension_failures(vec![basic_constraints(true), mapping(false)]),
+            ["rfc:cert_ext_pm_not_critical"]
+        );
+        assert_eq!(
+            extension_failures(vec![basic_constraints(false), mapping(true)]),
+            ["rfc:cert_ext_pm_not_ca"]
+        );
+        let to_any_policy = policy_mappings(true, &dv_policy(), &OID_ANY_POLICY);
+        assert_eq!(
+            extension_failures(vec![basic_constraints(true), to_any_policy]),
+            ["rfc:cert_ext_pm_anypolicy"]
+        );
+    }
+
+    #[test]
+    fn policy_constraints_lints() {
+        let require_explicit_policy = seq(&[&der(0x80, &[0])]);
+        let constraints =
+            |critical, value: &[u8]| extension(&OID_X509_EXT_POLICY_CONSTRAINTS, critical, value);
+        assert!(extension_failures(vec![
+            basic_constraints(true),
+            constraints(true, &require_explicit_policy)
+        ])
+        .is_empty());
+        assert_eq!(
+            extension_failures(vec![
+                basic_constraints(true),
+                constraints(false, &require_explicit_policy)
+            ]),
+            ["rfc:cert_ext_pc_not_critical"]
+        );
+        assert_eq!(
+            extension_failures(vec![basic_constraints(true), constraints(true, &seq(&[]))]),
+            ["rfc:cert_ext_pc_empty"]
+        );
+    }
+
+    #[test]
+    fn inhibit_any_policy_lints() {
+        let inhibit_any_policy =
+            |critical| extension(&OID_X509_EXT_INHIBIT_ANY_POLICY, critical, &int(&[0]));
+        assert!(
+            extension_failures(vec![basic_constraints(true), inhibit_any_policy(true)]).is_empty()
+        );
+        assert_eq!(
+            extension_failures(vec![basic_constraints(true), inhibit_any_policy(false)]),
+            ["rfc:cert_ext_iap_not_critical"]
+        );
+        assert_eq!(
+            extension_failures(vec![basic_constraints(false), inhibit_any_policy(true)]),
+            ["rfc:cert_ext_iap_not_ca"]
+        );
+    }
+}
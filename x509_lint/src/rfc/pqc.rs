@@ -0,0 +1,516 @@
+use x509_parser::asn1_rs::{Any, FromDer, Oid, Tag};
+use x509_parser::extensions::ParsedExtension;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+use x509_parser::x509::AlgorithmIdentifier;
+
+use super::oids::*;
+use crate::*;
+
+pub(crate) const PQC_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (SPKI_PQC_PARAMS_PRESENT, spki_pqc_params_present),
+    (SPKI_PQC_KEY_LENGTH, spki_pqc_key_length),
+    (SPKI_PQC_SIGNATURE_KEY_USAGE, spki_pqc_signature_key_usage),
+    (SPKI_ML_KEM_KEY_USAGE, spki_ml_kem_key_usage),
+    (SPKI_COMPOSITE_INVALID, spki_composite_invalid),
+    (SIGNATURE_PQC_PARAMS_PRESENT, signature_pqc_params_present),
+    (SIGNATURE_PQC_LENGTH, signature_pqc_length),
+    (SIGNATURE_COMPOSITE_INVALID, signature_composite_invalid),
+];
+
+pub(crate) const CRL_PQC_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (
+        CRL_SIGNATURE_PQC_PARAMS_PRESENT,
+        crl_signature_pqc_params_present,
+    ),
+    (CRL_SIGNATURE_PQC_LENGTH, crl_signature_pqc_length),
+    (
+        CRL_SIGNATURE_COMPOSITE_INVALID,
+        crl_signature_composite_invalid,
+    ),
+];
+
+/// ML-DSA and SLH-DSA parameter sets, with the length of public keys and signatures (in bytes)
+///
+/// Lengths are from FIPS 204 (table 2) and FIPS 205 (table 2).
+const PQC_SIGNATURES: &[(Oid<'static>, &str, usize, usize)] = &[
+    (OID_ML_DSA_44, "ML-DSA-44", 1312, 2420),
+    (OID_ML_DSA_65, "ML-DSA-65", 1952, 3309),
+    (OID_ML_DSA_87, "ML-DSA-87", 2592, 4627),
+    (OID_SLH_DSA_SHA2_128S, "SLH-DSA-SHA2-128s", 32, 7856),
+    (OID_SLH_DSA_SHA2_128F, "SLH-DSA-SHA2-128f", 32, 17088),
+    (OID_SLH_DSA_SHA2_192S, "SLH-DSA-SHA2-192s", 48, 16224),
+    (OID_SLH_DSA_SHA2_192F, "SLH-DSA-SHA2-192f", 48, 35664),
+    (OID_SLH_DSA_SHA2_256S, "SLH-DSA-SHA2-256s", 64, 29792),
+    (OID_SLH_DSA_SHA2_256F, "SLH-DSA-SHA2-256f", 64, 49856),
+    (OID_SLH_DSA_SHAKE_128S, "SLH-DSA-SHAKE-128s", 32, 7856),
+    (OID_SLH_DSA_SHAKE_128F, "SLH-DSA-SHAKE-128f", 32, 17088),
+    (OID_SLH_DSA_SHAKE_192S, "SLH-DSA-SHAKE-192s", 48, 16224),
+    (OID_SLH_DSA_SHAKE_192F, "SLH-DSA-SHAKE-192f", 48, 35664),
+    (OID_SLH_DSA_SHAKE_256S, "SLH-DSA-SHAKE-256s", 64, 29792),
+    (OID_SLH_DSA_SHAKE_256F, "SLH-DSA-SHAKE-256f", 64, 49856),
+];
+
+/// ML-KEM parameter sets, with the length of public keys (in bytes, FIPS 203 table 3)
+const ML_KEM: &[(Oid<'static>, &str, usize)] = &[
+    (OID_ML_KEM_512, "ML-KEM-512", 800),
+    (OID_ML_KEM_768, "ML-KEM-768", 1184),
+    (OID_ML_KEM_1024, "ML-KEM-1024", 1568),
+];
+
+/// Traditional component of a composite signature algorithm
+#[derive(Debug, Clone, Copy)]
+enum Traditional {
+    Rsa,
+    /// ECDSA, with the length of the uncompressed point
+    Ecdsa(usize),
+    Ed25519,
+    Ed448,
+}
+
+/// Composite ML-DSA algorithms, with their ML-DSA and traditional components
+const COMPOSITE_SIGNATURES: &[(Oid<'static>, Oid<'static>, Traditional)] = &[
+    (
+        OID_MLDSA44_RSA2048_PSS_SHA256,
+        OID_ML_DSA_44,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA44_RSA2048_PKCS15_SHA256,
+        OID_ML_DSA_44,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA44_ED25519_SHA512,
+        OID_ML_DSA_44,
+        Traditional::Ed25519,
+    ),
+    (
+        OID_MLDSA44_ECDSA_P256_SHA256,
+        OID_ML_DSA_44,
+        Traditional::Ecdsa(65),
+    ),
+    (
+        OID_MLDSA65_RSA3072_PSS_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA65_RSA3072_PKCS15_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA65_RSA4096_PSS_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA65_RSA4096_PKCS15_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA65_ECDSA_P256_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Ecdsa(65),
+    ),
+    (
+        OID_MLDSA65_ECDSA_P384_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Ecdsa(97),
+    ),
+    (
+        OID_MLDSA65_ECDSA_BRAINPOOL_P256R1_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Ecdsa(65),
+    ),
+    (
+        OID_MLDSA65_ED25519_SHA512,
+        OID_ML_DSA_65,
+        Traditional::Ed25519,
+    ),
+    (
+        OID_MLDSA87_ECDSA_P384_SHA512,
+        OID_ML_DSA_87,
+        Traditional::Ecdsa(97),
+    ),
+    (
+        OID_MLDSA87_ECDSA_BRAINPOOL_P384R1_SHA512,
+        OID_ML_DSA_87,
+        Traditional::Ecdsa(97),
+    ),
+    (
+        OID_MLDSA87_ED448_SHAKE256,
+        OID_ML_DSA_87,
+        Traditional::Ed448,
+    ),
+    (
+        OID_MLDSA87_RSA3072_PSS_SHA512,
+        OID_ML_DSA_87,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA87_RSA4096_PSS_SHA512,
+        OID_ML_DSA_87,
+        Traditional::Rsa,
+    ),
+    (
+        OID_MLDSA87_ECDSA_P521_SHA512,
+        OID_ML_DSA_87,
+        Traditional::Ecdsa(133),
+    ),
+];
+
+/// KeyUsage bits allowed for signature keys: digitalSignature, nonRepudiation, keyCertSign
+/// and cRLSign
+const SIGNATURE_KEY_USAGES: u16 = 0b110_0011;
+/// KeyUsage bit keyEncipherment
+const KEY_ENCIPHERMENT: u16 = 0b100;
+
+impl Traditional {
+    /// Check the traditional public key (DER RSAPublicKey, uncompressed EC point or raw key)
+    fn check_public_key(self, key: &[u8]) -> Result<(), String> {
+        match self {
+            Traditional::Rsa if !is_der_sequence(key) => Err("invalid RSA public key".into()),
+            Traditional::Ecdsa(len) if key.len() != len || key[0] != 4 => {
+                Err(format!("invalid EC point ({} bytes)", key.len()))
+            }
+            Traditional::Ed25519 => check_length("Ed25519 public key", key, 32),
+            Traditional::Ed448 => check_length("Ed448 public key", key, 57),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the traditional signature (raw RSA signature, DER Ecdsa-Sig-Value or raw signature)
+    fn check_signature(self, sig: &[u8]) -> Result<(), String> {
+        match self {
+            Traditional::Ecdsa(_) if !is_der_sequence(sig) => Err("invalid ECDSA signature".into()),
+            Traditional::Ed25519 => check_length("Ed25519 signature", sig, 64),
+            Traditional::Ed448 => check_length("Ed448 signature", sig, 114),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_der_sequence(data: &[u8]) -> bool {
+    matches!(Any::from_der(data), Ok((rem, any)) if rem.is_empty() && any.tag() == Tag::Sequence)
+}
+
+fn check_length(label: &str, data: &[u8], expected: usize) -> Result<(), String> {
+    if data.len() != expected {
+        return Err(format!(
+            "{label}: {} bytes, expected {expected}",
+            data.len()
+        ));
+    }
+    Ok(())
+}
+
+/// Return true if the algorithm is one of the post-quantum or composite algorithms
+fn is_pqc_algorithm(oid: &Oid) -> bool {
+    PQC_SIGNATURES.iter().any(|(o, ..)| o == oid)
+        || ML_KEM.iter().any(|(o, ..)| o == oid)
+        || COMPOSITE_SIGNATURES.iter().any(|(o, ..)| o == oid)
+}
+
+fn is_pqc_signature_key(oid: &Oid) -> bool {
+    PQC_SIGNATURES.iter().any(|(o, ..)| o == oid)
+        || COMPOSITE_SIGNATURES.iter().any(|(o, ..)| o == oid)
+}
+
+/// Return the ML-DSA public key and signature lengths of a composite algorithm
+fn composite_components(oid: &Oid) -> Option<(usize, usize, Traditional)> {
+    let (_, ml_dsa, traditional) = COMPOSITE_SIGNATURES.iter().find(|(o, ..)| o == oid)?;
+    let (.., key_len, sig_len) = PQC_SIGNATURES.iter().find(|(o, ..)| o == ml_dsa)?;
+    Some((*key_len, *sig_len, *traditional))
+}
+
+/// Split a composite value into its ML-DSA and traditional components
+fn split_composite(data: &[u8], ml_dsa_len: usize) -> Result<(&[u8], &[u8]), String> {
+    if data.len() <= ml_dsa_len {
+        return Err(format!("{} bytes, too short", data.len()));
+    }
+    Ok(data.split_at(ml_dsa_len))
+}
+
+fn check_signature_params(alg: &AlgorithmIdentifier) -> LintResult {
+    if is_pqc_algorithm(&alg.algorithm) && alg.parameters.is_some() {
+        let details = LintDetails::new(alg.algorithm.to_id_string());
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+fn check_signature_length(alg: &AlgorithmIdentifier, sig: &[u8]) -> LintResult {
+    let Some((_, name, _, len)) = PQC_SIGNATURES
+        .iter()
+        .find(|(oid, ..)| *oid == alg.algorithm)
+    else {
+        return LintResult::pass();
+    };
+    match check_length(name, sig, *len) {
+        Ok(()) => LintResult::pass(),
+        Err(e) => LintResult::new_details(LintStatus::Error, LintDetails::new(e)),
+    }
+}
+
+fn check_composite_signature(alg: &AlgorithmIdentifier, sig: &[u8]) -> LintResult {
+    let Some((_, sig_len, traditional)) = composite_components(&alg.algorithm) else {
+        return LintResult::pass();
+    };
+    let res = split_composite(sig, sig_len)
+        .and_then(|(_, traditional_sig)| traditional.check_signature(traditional_sig));
+    match res {
+        Ok(()) => LintResult::pass(),
+        Err(e) => LintResult::new_details(LintStatus::Error, LintDetails::new(e)),
+    }
+}
+
+/// Return the KeyUsage bits, if the extension is present
+fn key_usage_flags(x509: &X509Certificate) -> Option<u16> {
+    x509.extensions()
+        .iter()
+        .find_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::KeyUsage(ku) => Some(ku.flags),
+            _ => None,
+        })
+}
+
+lint_definition!(
+    SPKI_PQC_PARAMS_PRESENT,
+    "rfc:spki_pqc_params_present",
+    "For ML-DSA, SLH-DSA, ML-KEM and composite ML-DSA public keys, the parameters MUST be absent",
+    "draft-ietf-lamps-dilithium-certificates, draft-ietf-lamps-x509-slhdsa, draft-ietf-lamps-kyber-certificates"
+);
+certificate_lint!(
+    pub(super) spki_pqc_params_present,
+    LintStatus::Error,
+    |x509: &X509Certificate| {
+        let alg = &x509.public_key().algorithm;
+        is_pqc_algorithm(&alg.algorithm) && alg.parameters.is_some()
+    }
+);
+
+lint_definition!(
+    SPKI_PQC_KEY_LENGTH,
+    "rfc:spki_pqc_key_length",
+    "Invalid public key length for the ML-DSA, SLH-DSA or ML-KEM parameter set",
+    "FIPS 203, FIPS 204, FIPS 205"
+);
+pub(super) fn spki_pqc_key_length(x509: &X509Certificate) -> LintResult {
+    let spki = x509.public_key();
+    let oid = &spki.algorithm.algorithm;
+    let expected = PQC_SIGNATURES
+        .iter()
+        .find(|(o, ..)| o == oid)
+        .map(|(_, name, len, _)| (name, len))
+        .or_else(|| {
+            ML_KEM
+                .iter()
+                .find(|(o, ..)| o == oid)
+                .map(|(_, name, len)| (name, len))
+        });
+    let Some((name, len)) = expected else {
+        return LintResult::pass();
+    };
+    match check_length(name, &spki.subject_public_key.data, *len) {
+        Ok(()) => LintResult::pass(),
+        Err(e) => LintResult::new_details(LintStatus::Error, LintDetails::new(e)),
+    }
+}
+
+lint_definition!(
+    SPKI_PQC_SIGNATURE_KEY_USAGE,
+    "rfc:spki_pqc_signature_key_usage",
+    "KeyUsage of ML-DSA, SLH-DSA and composite ML-DSA keys MUST only contain digitalSignature, nonRepudiation, keyCertSign or cRLSign",
+    "draft-ietf-lamps-dilithium-certificates, draft-ietf-lamps-x509-slhdsa"
+);
+certificate_lint!(
+    pub(super) spki_pqc_signature_key_usage,
+    LintStatus::Error,
+    |x509: &X509Certificate| {
+        is_pqc_signature_key(&x509.public_key().algorithm.algorithm)
+            && key_usage_flags(x509).is_some_and(|flags| {
+                flags & SIGNATURE_KEY_USAGES == 0 || flags & !SIGNATURE_KEY_USAGES != 0
+            })
+    }
+);
+
+lint_definition!(
+    SPKI_ML_KEM_KEY_USAGE,
+    "rfc:spki_ml_kem_key_usage",
+    "KeyUsage MUST be present in certificates with ML-KEM keys, and keyEncipherment MUST be the only key usage set",
+    "draft-ietf-lamps-kyber-certificates"
+);
+pub(super) fn spki_ml_kem_key_usage(x509: &X509Certificate) -> LintResult {
+    let oid = &x509.public_key().algorithm.algorithm;
+    if !ML_KEM.iter().any(|(o, ..)| o == oid) {
+        return LintResult::pass();
+    }
+    match key_usage_flags(x509) {
+        Some(KEY_ENCIPHERMENT) => LintResult::pass(),
+        Some(_) => LintResult::new(LintStatus::Error),
+        None => LintResult::new_details(LintStatus::Error, "keyUsage is absent".into()),
+    }
+}
+
+lint_definition!(
+    SPKI_COMPOSITE_INVALID,
+    "rfc:spki_composite_invalid",
+    "Invalid composite ML-DSA public key",
+    "draft-ietf-lamps-pq-composite-sigs"
+);
+pub(super) fn spki_composite_invalid(x509: &X509Certificate) -> LintResult {
+    let spki = x509.public_key();
+    let Some((key_len, _, traditional)) = composite_components(&spki.algorithm.algorithm) else {
+        return LintResult::pass();
+    };
+    let res = split_composite(&spki.subject_public_key.data, key_len)
+        .and_then(|(_, traditional_key)| traditional.check_public_key(traditional_key));
+    match res {
+        Ok(()) => LintResult::pass(),
+        Err(e) => LintResult::new_details(LintStatus::Error, LintDetails::new(e)),
+    }
+}
+
+lint_definition!(
+    SIGNATURE_PQC_PARAMS_PRESENT,
+    "rfc:signature_pqc_params_present",
+    "For ML-DSA, SLH-DSA and composite ML-DSA signature algorithms, the parameters MUST be absent",
+    "draft-ietf-lamps-dilithium-certificates, draft-ietf-lamps-x509-slhdsa"
+);
+pub(super) fn signature_pqc_params_present(x509: &X509Certificate) -> LintResult {
+    check_signature_params(&x509.signature_algorithm)
+}
+
+lint_definition!(
+    SIGNATURE_PQC_LENGTH,
+    "rfc:signature_pqc_length",
+    "Invalid signature length for the ML-DSA or SLH-DSA parameter set",
+    "FIPS 204, FIPS 205"
+);
+pub(super) fn signature_pqc_length(x509: &X509Certificate) -> LintResult {
+    check_signature_length(&x509.signature_algorithm, &x509.signature_value.data)
+}
+
+lint_definition!(
+    SIGNATURE_COMPOSITE_INVALID,
+    "rfc:signature_composite_invalid",
+    "Invalid composite ML-DSA signature value",
+    "draft-ietf-lamps-pq-composite-sigs"
+);
+pub(super) fn signature_composite_invalid(x509: &X509Certificate) -> LintResult {
+    check_composite_signature(&x509.signature_algorithm, &x509.signature_value.data)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_PQC_PARAMS_PRESENT,
+    "rfc:crl_signature_pqc_params_present",
+    "For ML-DSA, SLH-DSA and composite ML-DSA signature algorithms, the parameters MUST be absent",
+    "draft-ietf-lamps-dilithium-certificates, draft-ietf-lamps-x509-slhdsa"
+);
+pub(super) fn crl_signature_pqc_params_present(crl: &CertificateRevocationList) -> LintResult {
+    check_signature_params(&crl.signature_algorithm)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_PQC_LENGTH,
+    "rfc:crl_signature_pqc_length",
+    "Invalid signature length for the ML-DSA or SLH-DSA parameter set",
+    "FIPS 204, FIPS 205"
+);
+pub(super) fn crl_signature_pqc_length(crl: &CertificateRevocationList) -> LintResult {
+    check_signature_length(&crl.signature_algorithm, &crl.signature_value.data)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_COMPOSITE_INVALID,
+    "rfc:crl_signature_composite_invalid",
+    "Invalid composite ML-DSA signature value",
+    "draft-ietf-lamps-pq-composite-sigs"
+);
+pub(super) fn crl_signature_composite_invalid(crl: &CertificateRevocationList) -> LintResult {
+    check_composite_signature(&crl.signature_algorithm, &crl.signature_value.data)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::OID_X509_EXT_KEY_USAGE;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn pqc_certificate(
+        alg: &Oid,
+        params: bool,
+        key_len: usize,
+        key_usage: Option<&[u8]>,
+    ) -> Vec<u8> {
+        let algorithm = if params {
+            seq(&[&oid(alg), &der(0x05, &[])])
+        } else {
+            seq(&[&oid(alg)])
+        };
+        let extensions = key_usage
+            .map(|bits| extension(&OID_X509_EXT_KEY_USAGE, true, &der(0x03, bits)))
+            .into_iter()
+            .collect();
+        CertificateBuilder {
+            spki: seq(&[&algorithm, &bits(&vec![0x42; key_len])]),
+            extensions,
+            ..Default::default()
+        }
+        .build()
+    }
+
+    #[test]
+    fn pqc_key_lints() {
+        // digitalSignature, keyEncipherment, and both
+        let digital_signature: &[u8] = &[7, 0x80];
+        let key_encipherment: &[u8] = &[5, 0x20];
+        let both: &[u8] = &[5, 0xa0];
+
+        let data = pqc_certificate(&OID_ML_DSA_44, false, 1312, Some(digital_signature));
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(PQC_LINTS, &x509).is_empty());
+        let data = pqc_certificate(&OID_SLH_DSA_SHA2_128S, false, 32, None);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(PQC_LINTS, &x509).is_empty());
+        let data = pqc_certificate(&OID_ML_KEM_768, false, 1184, Some(key_encipherment));
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(PQC_LINTS, &x509).is_empty());
+
+        let data = pqc_certificate(&OID_ML_DSA_44, true, 1311, Some(both));
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(PQC_LINTS, &x509),
+            [
+                "rfc:spki_pqc_params_present",
+                "rfc:spki_pqc_key_length",
+                "rfc:spki_pqc_signature_key_usage",
+            ]
+        );
+        let data = pqc_certificate(&OID_ML_DSA_65, false, 1952, Some(key_encipherment));
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(PQC_LINTS, &x509),
+            ["rfc:spki_pqc_signature_key_usage"]
+        );
+        let data = pqc_certificate(&OID_ML_KEM_512, true, 1184, Some(both));
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(PQC_LINTS, &x509),
+            [
+                "rfc:spki_pqc_params_present",
+                "rfc:spki_pqc_key_length",
+                "rfc:spki_ml_kem_key_usage",
+            ]
+        );
+        let data = pqc_certificate(&OID_ML_KEM_1024, false, 1568, None);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(PQC_LINTS, &x509),
+            ["rfc:spki_ml_kem_key_usage"]
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::collections::HashSet;
+
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{CertificateRevocationList, RevokedCertificate};
+use x509_parser::x509::{ReasonCode, X509Version};
+
+use crate::*;
+
+pub(crate) const CRL_REVOKED_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_REVOKED_DUPLICATE_SERIAL, crl_revoked_duplicate_serial),
+    (
+        CRL_REVOKED_DATE_AFTER_THIS_UPDATE,
+        crl_revoked_date_after_this_update,
+    ),
+    (CRL_V1_ENTRY_EXTENSIONS, crl_v1_entry_extensions),
+    (CRL_REASON_CODE_UNSPECIFIED, crl_reason_code_unspecified),
+    (
+        CRL_REASON_REMOVE_FROM_CRL_NOT_DELTA,
+        crl_reason_remove_from_crl_not_delta,
+    ),
+    (
+        CRL_INVALIDITY_DATE_AFTER_REVOCATION,
+        crl_invalidity_date_after_revocation,
+    ),
+    (
+        CRL_CERTIFICATE_ISSUER_NOT_CRITICAL,
+        crl_certificate_issuer_not_critical,
+    ),
+];
+
+/// Return the first revoked entry for which `check` returns an error, with the entry serial
+fn check_revoked(
+    crl: &CertificateRevocationList,
+    status: LintStatus,
+    check: impl Fn(&RevokedCertificate) -> Result<(), String>,
+) -> LintResult {
+    for revoked in crl.iter_revoked_certificates() {
+        if let Err(e) = check(revoked) {
+            let details = LintDetails::new(format!("{}: {e}", revoked.raw_serial_as_string()));
+            return LintResult::new_details(status, details);
+        }
+    }
+    LintResult::pass()
+}
+
+/// Return true if the CRL is a delta CRL
+fn is_delta_crl(crl: &CertificateRevocationList) -> bool {
+    crl.extensions()
+        .iter()
+        .any(|ext| ext.oid == OID_X509_EXT_DELTA_CRL_INDICATOR)
+}
+
+lint_definition!(
+    CRL_REVOKED_DUPLICATE_SERIAL,
+    "rfc:crl_revoked_duplicate_serial",
+    "A certificate serial number SHOULD appear only once in a CRL",
+    "RFC5280: 5.1.2.6"
+);
+pub(super) fn crl_revoked_duplicate_serial(crl: &CertificateRevocationList) -> LintResult {
+    // in indirect CRLs, the certificateIssuer extension applies to all following entries
+    let mut issuer = None;
+    let mut seen = HashSet::new();
+    for revoked in crl.iter_revoked_certificates() {
+        if let Some(ext) = revoked.find_extension(&OID_X509_EXT_ISSUER) {
+            issuer = Some(ext.value);
+        }
+        if !seen.insert((issuer, revoked.raw_serial())) {
+            let details = LintDetails::new(revoked.raw_serial_as_string());
+            return LintResult::new_details(LintStatus::Warn, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_REVOKED_DATE_AFTER_THIS_UPDATE,
+    "rfc:crl_revoked_date_after_this_update",
+    "Revocation date MUST NOT be later than the CRL thisUpdate",
+    "RFC5280: 5.1.2.6"
+);
+pub(super) fn crl_revoked_date_after_this_update(crl: &CertificateRevocationList) -> LintResult {
+    let this_update = crl.last_update();
+    check_revoked(crl, LintStatus::Error, |revoked| {
+        if revoked.revocation_date > this_update {
+            return Err(format!("revoked on {}", revoked.revocation_date));
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    CRL_V1_ENTRY_EXTENSIONS,
+    "rfc:crl_v1_entry_extensions",
+    "CRL entry extensions MUST NOT be present in a version 1 CRL",
+    "RFC5280: 5.1.2.1"
+);
+pub(super) fn crl_v1_entry_extensions(crl: &CertificateRevocationList) -> LintResult {
+    if crl.version() == Some(X509Version::V2) {
+        return LintResult::pass();
+    }
+    check_revoked(crl, LintStatus::Error, |revoked| {
+        if !revoked.extensions().is_empty() {
+            return Err("entry extensions present".to_string());
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    CRL_REASON_CODE_UNSPECIFIED,
+    "rfc:crl_reason_code_unspecified",
+    "The reasonCode extension SHOULD be absent instead of using the unspecified (0) value",
+    "RFC5280: 5.3.1"
+);
+pub(super) fn crl_reason_code_unspecified(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked(crl, LintStatus::Warn, |revoked| {
+        if let Some((_, ReasonCode::Unspecified)) = revoked.reason_code() {
+            return Err("reasonCode unspecified".to_string());
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    CRL_REASON_REMOVE_FROM_CRL_NOT_DELTA,
+    "rfc:crl_reason_remove_from_crl_not_delta",
+    "The removeFromCRL (8) reason code may only appear in delta CRLs",
+    "RFC5280: 5.3.1"
+);
+pub(super) fn crl_reason_remove_from_crl_not_delta(crl: &CertificateRevocationList) -> LintResult {
+    if is_delta_crl(crl) {
+        return LintResult::pass();
+    }
+    check_revoked(crl, LintStatus::Error, |revoked| {
+        if let Some((_, ReasonCode::RemoveFromCRL)) = revoked.reason_code() {
+            return Err("reasonCode removeFromCRL".to_string());
+        }
+        Ok(())
+    })
+}
+
+lint_definition!(
+    CRL_INVALIDITY_DATE_AFTER_REVOCATION,
+    "rfc:crl_invalidity_date_after_revocation",
+    "The invalidityDate SHOULD NOT be later than the revocation date",
+    "RFC5280: 5.3.2"
+);
+pub(super) fn crl_invalidity_date_after_revocation(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked(crl, LintStatus::Warn, |revoked| {
+        match revoked.invalidity_date() {
+            Some((_, date)) if date > revoked.revocation_date => {
+                Err(format!("invalidityDate {date}"))
+            }
+            _ => Ok(()),
+        }
+    })
+}
+
+lint_definition!(
+    CRL_CERTIFICATE_ISSUER_NOT_CRITICAL,
+    "rfc:crl_certificate_issuer_not_critical",
+    "CRL issuers MUST mark the certificateIssuer entry extension as critical",
+    "RFC5280: 5.3.3"
+);
+pub(super) fn crl_certificate_issuer_not_critical(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked(crl, LintStatus::Error, |revoked| {
+        match revoked.find_extension(&OID_X509_EXT_ISSUER) {
+            Some(ext) if !ext.critical => Err("certificateIssuer not critical".to_string()),
+            _ => Ok(()),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn reason_code(reason: u8) -> Vec<u8> {
+        extension(&OID_X509_EXT_REASON_CODE, false, &der(0x0a, &[reason]))
+    }
+
+    fn certificate_issuer(critical: bool, cn: &str) -> Vec<u8> {
+        extension(
+            &OID_X509_EXT_ISSUER,
+            critical,
+            &seq(&[&der(0xa4, &name(cn))]),
+        )
+    }
+
+    fn crl_revoked_failures(crl: CrlBuilder) -> Vec<String> {
+        let data = crl.build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        crl_lint_failures(CRL_REVOKED_LINTS, &crl)
+    }
+
+    #[test]
+    fn revoked_lints() {
+        let invalidity_date = extension(
+            &OID_X509_EXT_INVALIDITY_DATE,
+            false,
+            &der(0x18, b"20241201000000Z"),
+        );
+        let revoked = vec![
+            revoked_entry(&[1], &[reason_code(1), invalidity_date]),
+            revoked_entry(&[2], &[]),
+            // same serial, from another issuer (indirect CRL)
+            revoked_entry(&[3], &[certificate_issuer(true, "Other CA")]),
+            revoked_entry(&[1], &[]),
+        ];
+        let crl = CrlBuilder {
+            revoked,
+            ..Default::default()
+        };
+        assert!(crl_revoked_failures(crl).is_empty());
+
+        let invalidity_date = extension(
+            &OID_X509_EXT_INVALIDITY_DATE,
+            false,
+            &der(0x18, b"20241220000000Z"),
+        );
+        let revoked = vec![
+            revoked_entry(&[1], &[reason_code(0)]),
+            revoked_entry(&[2], &[reason_code(8), invalidity_date]),
+            revoked_entry(&[3], &[certificate_issuer(false, "Other CA")]),
+            revoked_entry(&[3], &[]),
+            seq(&[&int(&[4]), &der(0x17, b"250102000000Z")]),
+        ];
+        let crl = CrlBuilder {
+            revoked,
+            ..Default::default()
+        };
+        assert_eq!(
+            crl_revoked_failures(crl),
+            [
+                "rfc:crl_revoked_duplicate_serial",
+                "rfc:crl_revoked_date_after_this_update",
+                "rfc:crl_reason_code_unspecified",
+                "rfc:crl_reason_remove_from_crl_not_delta",
+                "rfc:crl_invalidity_date_after_revocation",
+                "rfc:crl_certificate_issuer_not_critical",
+            ]
+        );
+
+        // removeFromCRL is allowed in delta CRLs
+        let delta = extension(&OID_X509_EXT_DELTA_CRL_INDICATOR, true, &int(&[1]));
+        let crl = CrlBuilder {
+            revoked: vec![revoked_entry(&[1], &[reason_code(8)])],
+            extensions: vec![delta],
+            ..Default::default()
+        };
+        assert!(crl_revoked_failures(crl).is_empty());
+
+        let crl = CrlBuilder {
+            version: None,
+            revoked: vec![revoked_entry(&[1], &[reason_code(1)])],
+            ..Default::default()
+        };
+        assert_eq!(crl_revoked_failures(crl), ["rfc:crl_v1_entry_extensions"]);
+    }
+}
@@ -1,6 +1,7 @@
 use x509_parser::x509::X509Version;
 use x509_parser::{certificate::X509Certificate, prelude::CertificateRevocationList};
 
+use super::helpers::algorithm_identifier_der;
 use super::{CertificateLint, LintDefinition, LintResult, LintStatus};
 use crate::{certificate_lint, crl_lint, lint_definition, CRLLint};
 
@@ -19,15 +20,12 @@ pub(super) const RFC_LINTS: &[(LintDefinition, CertificateLint)] = &[
     (CHECK_YEAR_POST2049_UTC, check_notafter_generalizedtime_2049),
     (CHECK_ISSUERID_V1, check_issuer_uniqueid_v1),
     (CHECK_SUBJECTID_V1, check_subject_uniqueid_v1),
-    (CHECK_MATCHING_SIG_ALGS, signature_algorithms_must_match_oid),
+    (CHECK_MATCHING_SIG_ALGS, signature_algorithms_must_match),
 ];
 
 pub(super) const CRL_RFC_LINTS: &[(LintDefinition, CRLLint)] = &[
     (CHECK_VERSION, crl_version),
-    (
-        CRL_MATCHING_SIG_ALGS,
-        crl_signature_algorithms_must_match_oid,
-    ),
+    (CRL_MATCHING_SIG_ALGS, crl_signature_algorithms_must_match),
 ];
 
 lint_definition!(CHECK_VERSION, "rfc:check_version", "Invalid X.509 version");
@@ -164,11 +162,13 @@ lint_definition!(
     in the sequence tbsCertificate",
     "RFC5280: 4.1.1.2"
 );
-fn signature_algorithms_must_match_oid(x509: &X509Certificate) -> LintResult {
+fn signature_algorithms_must_match(x509: &X509Certificate) -> LintResult {
     let sig = &x509.signature_algorithm;
     let sig_tbs = &x509.tbs_certificate.signature;
     if sig.algorithm != sig_tbs.algorithm {
         LintResult::new_details(LintStatus::Error, "OID".into())
+    } else if algorithm_identifier_der(sig) != algorithm_identifier_der(sig_tbs) {
+        LintResult::new_details(LintStatus::Error, "parameters".into())
     } else {
         LintResult::pass()
     }
@@ -187,12 +187,65 @@ lint_definition!(
     in the sequence CertificateList",
     "RFC5280: 5.1.1.2"
 );
-fn crl_signature_algorithms_must_match_oid(crl: &CertificateRevocationList) -> LintResult {
+fn crl_signature_algorithms_must_match(crl: &CertificateRevocationList) -> LintResult {
     let sig = &crl.signature_algorithm;
     let sig_tbs = &crl.tbs_cert_list.signature;
     if sig.algorithm != sig_tbs.algorithm {
         LintResult::new_details(LintStatus::Error, "OID".into())
+    } else if algorithm_identifier_der(sig) != algorithm_identifier_der(sig_tbs) {
+        LintResult::new_details(LintStatus::Error, "parameters".into())
     } else {
         LintResult::pass()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::*;
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    /// Replace the signatureAlgorithm field (outside of the signed data)
+    fn with_signature_algorithm(tbs: &[u8], signature_algorithm: &[u8]) -> Vec<u8> {
+        seq(&[tbs, signature_algorithm, &bits(&[0; 8])])
+    }
+
+    #[test]
+    fn matching_signature_algorithms() {
+        let ecdsa_sha256 = seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256)]);
+        let ecdsa_sha256_null = seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256), &der(0x05, &[])]);
+        let ecdsa_sha384 = seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA384)]);
+
+        let data = CertificateBuilder::default().build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        let tbs = x509.tbs_certificate.as_ref();
+        for (signature_algorithm, expected) in [
+            (&ecdsa_sha256, None),
+            (&ecdsa_sha256_null, Some("parameters")),
+            (&ecdsa_sha384, Some("OID")),
+        ] {
+            let data = with_signature_algorithm(tbs, signature_algorithm);
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            let encoded = algorithm_identifier_der(&x509.signature_algorithm);
+            assert_eq!(encoded.as_ref(), Some(signature_algorithm));
+            let result = signature_algorithms_must_match(&x509);
+            assert_eq!(result.details.map(|d| d.to_string()).as_deref(), expected);
+        }
+
+        let data = CrlBuilder::default().build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        let tbs = crl.tbs_cert_list.as_ref();
+        for (signature_algorithm, expected) in [
+            (&ecdsa_sha256, None),
+            (&ecdsa_sha256_null, Some("parameters")),
+            (&ecdsa_sha384, Some("OID")),
+        ] {
+            let data = with_signature_algorithm(tbs, signature_algorithm);
+            let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+            let result = crl_signature_algorithms_must_match(&crl);
+            assert_eq!(result.details.map(|d| d.to_string()).as_deref(), expected);
+        }
+    }
+}
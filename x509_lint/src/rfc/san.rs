@@ -0,0 +1,234 @@
+use x509_parser::extensions::*;
+use x509_parser::prelude::X509Certificate;
+
+use super::helpers::{check_dns_name, check_rfc822_name, check_uri};
+use crate::*;
+
+pub(crate) const SAN_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (CERT_EXT_SAN_EMPTY, cert_ext_san_empty),
+    (
+        CERT_EXT_SAN_NOT_CRITICAL_EMPTY_SUBJECT,
+        cert_ext_san_not_critical_empty_subject,
+    ),
+    (CERT_EXT_SAN_DNSNAME_INVALID, cert_ext_san_dnsname_invalid),
+    (
+        CERT_EXT_SAN_IPADDRESS_INVALID_LENGTH,
+        cert_ext_san_ipaddress_invalid_length,
+    ),
+    (CERT_EXT_SAN_URI_INVALID, cert_ext_san_uri_invalid),
+    (CERT_EXT_SAN_RFC822_INVALID, cert_ext_san_rfc822_invalid),
+    (CERT_EXT_SAN_DUPLICATE, cert_ext_san_duplicate),
+];
+
+/// Iterate on all the names of all SubjectAltName extensions
+fn san_names<'a>(x509: &'a X509Certificate) -> impl Iterator<Item = &'a GeneralName<'a>> {
+    x509.extensions()
+        .iter()
+        .filter_map(|ext| match ext.parsed_extension() {
+            ParsedExtension::SubjectAlternativeName(san) => Some(san.general_names.iter()),
+            _ => None,
+        })
+        .flatten()
+}
+
+lint_definition!(
+    CERT_EXT_SAN_EMPTY,
+    "rfc:cert_ext_san_empty",
+    "If the subjectAltName extension is present, the sequence MUST contain at least one entry",
+    "RFC5280: 4.2.1.6"
+);
+pub(super) fn cert_ext_san_empty(x509: &X509Certificate) -> LintResult {
+    for ext in x509.extensions() {
+        if let ParsedExtension::SubjectAlternativeName(san) = ext.parsed_extension() {
+            if san.general_names.is_empty() {
+                return LintResult::new(LintStatus::Error);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SAN_NOT_CRITICAL_EMPTY_SUBJECT,
+    "rfc:cert_ext_san_not_critical_empty_subject",
+    "If the subject field contains an empty sequence, the subjectAltName extension MUST be critical",
+    "RFC5280: 4.2.1.6"
+);
+certificate_lint!(
+    pub(super) cert_ext_san_not_critical_empty_subject,
+    LintStatus::Error,
+    |x509: &X509Certificate| x509.subject().iter().count() == 0
+        && x509.extensions().iter().any(|ext| {
+            matches!(
+                ext.parsed_extension(),
+                ParsedExtension::SubjectAlternativeName(_)
+            ) && !ext.critical
+        })
+);
+
+lint_definition!(
+    CERT_EXT_SAN_DNSNAME_INVALID,
+    "rfc:cert_ext_san_dnsname_invalid",
+    "SubjectAltName dNSName MUST be in the preferred name syntax",
+    "RFC5280: 4.2.1.6"
+);
+pub(super) fn cert_ext_san_dnsname_invalid(x509: &X509Certificate) -> LintResult {
+    for name in san_names(x509) {
+        if let GeneralName::DNSName(s) = name {
+            if let Err(e) = check_dns_name(s) {
+                let details = LintDetails::new(format!("'{s}': {e}"));
+                return LintResult::new_details(LintStatus::Error, details);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SAN_IPADDRESS_INVALID_LENGTH,
+    "rfc:cert_ext_san_ipaddress_invalid_length",
+    "SubjectAltName iPAddress MUST contain exactly four (IPv4) or sixteen (IPv6) octets",
+    "RFC5280: 4.2.1.6"
+);
+pub(super) fn cert_ext_san_ipaddress_invalid_length(x509: &X509Certificate) -> LintResult {
+    for name in san_names(x509) {
+        if let GeneralName::IPAddress(b) = name {
+            if b.len() != 4 && b.len() != 16 {
+                let details = LintDetails::new(format!("length {}", b.len()));
+                return LintResult::new_details(LintStatus::Error, details);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SAN_URI_INVALID,
+    "rfc:cert_ext_san_uri_invalid",
+    "SubjectAltName uniformResourceIdentifier MUST be an absolute URI including a host",
+    "RFC5280: 4.2.1.6"
+);
+pub(super) fn cert_ext_san_uri_invalid(x509: &X509Certificate) -> LintResult {
+    for name in san_names(x509) {
+        if let GeneralName::URI(s) = name {
+            if let Err(e) = check_uri(s) {
+                let details = LintDetails::new(format!("'{s}': {e}"));
+                return LintResult::new_details(LintStatus::Error, details);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SAN_RFC822_INVALID,
+    "rfc:cert_ext_san_rfc822_invalid",
+    "SubjectAltName rfc822Name MUST be a valid Mailbox (addr-spec)",
+    "RFC5280: 4.2.1.6"
+);
+pub(super) fn cert_ext_san_rfc822_invalid(x509: &X509Certificate) -> LintResult {
+    for name in san_names(x509) {
+        if let GeneralName::RFC822Name(s) = name {
+            if let Err(e) = check_rfc822_name(s) {
+                let details = LintDetails::new(format!("'{s}': {e}"));
+                return LintResult::new_details(LintStatus::Error, details);
+            }
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CERT_EXT_SAN_DUPLICATE,
+    "rfc:cert_ext_san_duplicate",
+    "Duplicate entry in 'SubjectAltName'"
+);
+pub(super) fn cert_ext_san_duplicate(x509: &X509Certificate) -> LintResult {
+    let names: Vec<_> = san_names(x509).collect();
+    for (idx, name) in names.iter().enumerate() {
+        if names[..idx].contains(name) {
+            let details = LintDetails::new(format!("{name}"));
+            return LintResult::new_details(LintStatus::Warn, details);
+        }
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::oid_registry::OID_X509_EXT_SUBJECT_ALT_NAME;
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn san_failures(subject: Vec<u8>, critical: bool, names: &[&[u8]]) -> Vec<String> {
+        let san = extension(
+            &OID_X509_EXT_SUBJECT_ALT_NAME,
+            critical,
+            &der(0x30, &names.concat()),
+        );
+        let data = CertificateBuilder {
+            subject,
+            extensions: vec![san],
+            ..Default::default()
+        }
+        .build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        cert_lint_failures(SAN_LINTS, &x509)
+    }
+
+    fn failures(names: &[&[u8]]) -> Vec<String> {
+        san_failures(name("www.example.com"), false, names)
+    }
+
+    #[test]
+    fn san_structure() {
+        let dns_name = der(0x82, b"www.example.com");
+        assert!(failures(&[&dns_name]).is_empty());
+        assert_eq!(failures(&[]), ["rfc:cert_ext_san_empty"]);
+
+        let empty_subject = seq(&[]);
+        assert!(san_failures(empty_subject.clone(), true, &[&dns_name]).is_empty());
+        assert_eq!(
+            san_failures(empty_subject, false, &[&dns_name]),
+            ["rfc:cert_ext_san_not_critical_empty_subject"]
+        );
+
+        assert_eq!(
+            failures(&[&dns_name, &dns_name]),
+            ["rfc:cert_ext_san_duplicate"]
+        );
+    }
+
+    #[test]
+    fn san_name_syntax() {
+        let valid: [&[u8]; 4] = [
+            &der(0x81, b"user@example.com"),
+            &der(0x86, b"https://www.example.com/index.html"),
+            &der(0x87, &[192, 0, 2, 1]),
+            &der(
+                0x87,
+                &[0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1],
+            ),
+        ];
+        assert!(failures(&valid).is_empty());
+
+        assert_eq!(
+            failures(&[&der(0x82, b" ")]),
+            ["rfc:cert_ext_san_dnsname_invalid"]
+        );
+        assert_eq!(
+            failures(&[&der(0x86, b"https:///index.html")]),
+            ["rfc:cert_ext_san_uri_invalid"]
+        );
+        assert_eq!(
+            failures(&[&der(0x81, b"user.example.com")]),
+            ["rfc:cert_ext_san_rfc822_invalid"]
+        );
+        assert_eq!(
+            failures(&[&der(0x87, &[192, 0, 2, 1, 0])]),
+            ["rfc:cert_ext_san_ipaddress_invalid_length"]
+        );
+    }
+}
@@ -0,0 +1,304 @@
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+
+use super::helpers::{bit_length, strip_leading_zeroes};
+use crate::*;
+
+pub(crate) const SERIAL_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (SERIAL_TOO_LONG, serial_too_long),
+    (SERIAL_ZERO, serial_zero),
+    (SERIAL_TOO_SHORT, serial_too_short),
+    (SERIAL_LOW_ENTROPY, serial_low_entropy),
+];
+
+pub(crate) const CRL_SERIAL_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_REVOKED_SERIAL_TOO_LONG, crl_revoked_serial_too_long),
+    (CRL_REVOKED_SERIAL_ZERO, crl_revoked_serial_zero),
+    (CRL_REVOKED_SERIAL_NEGATIVE, crl_revoked_serial_negative),
+    (CRL_REVOKED_SERIAL_TOO_SHORT, crl_revoked_serial_too_short),
+    (
+        CRL_REVOKED_SERIAL_LOW_ENTROPY,
+        crl_revoked_serial_low_entropy,
+    ),
+];
+
+/// Minimum number of bits of a serial number (CABF requires 64 bits from a CSPRNG)
+const SERIAL_MIN_BITS: usize = 64;
+
+fn check_too_long(serial: &[u8]) -> Result<(), String> {
+    if serial.len() > 20 {
+        return Err(format!("{} octets", serial.len()));
+    }
+    Ok(())
+}
+
+fn check_zero(serial: &[u8]) -> Result<(), String> {
+    if !serial.is_empty() && serial.iter().all(|&b| b == 0) {
+        return Err("serial is zero".to_string());
+    }
+    Ok(())
+}
+
+fn check_negative(serial: &[u8]) -> Result<(), String> {
+    if serial.first().is_some_and(|b| b & 0x80 != 0) {
+        return Err("serial is negative".to_string());
+    }
+    Ok(())
+}
+
+fn check_too_short(serial: &[u8]) -> Result<(), String> {
+    // negative and empty serials are reported by other lints
+    if serial.is_empty() || serial[0] & 0x80 != 0 {
+        return Ok(());
+    }
+    let bits = bit_length(serial);
+    if bits < SERIAL_MIN_BITS {
+        return Err(format!("{bits} bits"));
+    }
+    Ok(())
+}
+
+/// Look for patterns unlikely to come from a random generator
+fn check_low_entropy(serial: &[u8]) -> Result<(), String> {
+    let b = strip_leading_zeroes(serial);
+    if b.len() < 2 {
+        return Ok(());
+    }
+    if b.iter().all(|&x| x == b[0]) {
+        return Err("all bytes are identical".to_string());
+    }
+    if b.len() >= 4 {
+        let steps: Vec<_> = b.windows(2).map(|w| w[1].wrapping_sub(w[0])).collect();
+        if steps.iter().all(|&s| s == 1) || steps.iter().all(|&s| s == 0xff) {
+            return Err("sequential bytes".to_string());
+        }
+    }
+    if is_timestamp_like(b) {
+        return Err("looks like a timestamp".to_string());
+    }
+    Ok(())
+}
+
+/// Return true if the value is a Unix timestamp (seconds or milliseconds), or a date
+/// `YYYYMMDDhhmmss` in decimal or BCD
+fn is_timestamp_like(b: &[u8]) -> bool {
+    // 2000-01-01 to 2100-01-01
+    const UNIX_RANGE: std::ops::Range<u64> = 946_684_800..4_102_444_800;
+    if b.len() <= 8 {
+        let value = b.iter().fold(0u64, |acc, &x| (acc << 8) | u64::from(x));
+        if UNIX_RANGE.contains(&value)
+            || UNIX_RANGE.contains(&(value / 1000))
+            || is_date_digits(&value.to_string())
+        {
+            return true;
+        }
+    }
+    let hex: String = b.iter().map(|x| format!("{x:02x}")).collect();
+    is_date_digits(hex.trim_start_matches('0'))
+}
+
+fn is_date_digits(s: &str) -> bool {
+    if s.len() != 14 || !s.bytes().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let field = |range: std::ops::Range<usize>| s[range].parse::<u32>().unwrap_or(u32::MAX);
+    (1970..=2100).contains(&field(0..4))
+        && (1..=12).contains(&field(4..6))
+        && (1..=31).contains(&field(6..8))
+        && field(8..10) < 24
+        && field(10..12) < 60
+        && field(12..14) < 60
+}
+
+/// Run `check` on the serial of all revoked certificates
+fn check_revoked_serials(
+    crl: &CertificateRevocationList,
+    status: LintStatus,
+    check: impl Fn(&[u8]) -> Result<(), String>,
+) -> LintResult {
+    for revoked in crl.iter_revoked_certificates() {
+        if let Err(e) = check(revoked.raw_serial()) {
+            let details = LintDetails::new(format!("{}: {e}", revoked.raw_serial_as_string()));
+            return LintResult::new_details(status, details);
+        }
+    }
+    LintResult::pass()
+}
+
+fn check_cert_serial(
+    x509: &X509Certificate,
+    status: LintStatus,
+    check: impl Fn(&[u8]) -> Result<(), String>,
+) -> LintResult {
+    match check(x509.raw_serial()) {
+        Ok(()) => LintResult::pass(),
+        Err(e) => LintResult::new_details(status, LintDetails::new(e)),
+    }
+}
+
+lint_definition!(
+    SERIAL_TOO_LONG,
+    "rfc:serial_too_long",
+    "Conforming CAs MUST NOT use serialNumber values longer than 20 octets",
+    "RFC5280: 4.1.2.2"
+);
+pub(super) fn serial_too_long(x509: &X509Certificate) -> LintResult {
+    check_cert_serial(x509, LintStatus::Error, check_too_long)
+}
+
+lint_definition!(
+    SERIAL_ZERO,
+    "rfc:serial_zero",
+    "The serial number MUST be a positive integer",
+    "RFC5280: 4.1.2.2"
+);
+pub(super) fn serial_zero(x509: &X509Certificate) -> LintResult {
+    check_cert_serial(x509, LintStatus::Error, check_zero)
+}
+
+lint_definition!(
+    SERIAL_TOO_SHORT,
+    "rfc:serial_too_short",
+    "Serial Number has fewer than 64 bits",
+    "CABF BR: 7.1"
+);
+pub(super) fn serial_too_short(x509: &X509Certificate) -> LintResult {
+    check_cert_serial(x509, LintStatus::Warn, check_too_short)
+}
+
+lint_definition!(
+    SERIAL_LOW_ENTROPY,
+    "rfc:serial_low_entropy",
+    "Serial Number does not look randomly generated",
+    "CABF BR: 7.1"
+);
+pub(super) fn serial_low_entropy(x509: &X509Certificate) -> LintResult {
+    check_cert_serial(x509, LintStatus::Warn, check_low_entropy)
+}
+
+lint_definition!(
+    CRL_REVOKED_SERIAL_TOO_LONG,
+    "rfc:crl_revoked_serial_too_long",
+    "Revoked certificate serial number is longer than 20 octets",
+    "RFC5280: 5.1.2.6"
+);
+pub(super) fn crl_revoked_serial_too_long(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked_serials(crl, LintStatus::Error, check_too_long)
+}
+
+lint_definition!(
+    CRL_REVOKED_SERIAL_ZERO,
+    "rfc:crl_revoked_serial_zero",
+    "Revoked certificate serial number is zero",
+    "RFC5280: 4.1.2.2"
+);
+pub(super) fn crl_revoked_serial_zero(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked_serials(crl, LintStatus::Error, check_zero)
+}
+
+lint_definition!(
+    CRL_REVOKED_SERIAL_NEGATIVE,
+    "rfc:crl_revoked_serial_negative",
+    "Revoked certificate serial number is negative",
+    "RFC5280: 4.1.2.2"
+);
+pub(super) fn crl_revoked_serial_negative(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked_serials(crl, LintStatus::Error, check_negative)
+}
+
+lint_definition!(
+    CRL_REVOKED_SERIAL_TOO_SHORT,
+    "rfc:crl_revoked_serial_too_short",
+    "Revoked certificate serial number has fewer than 64 bits",
+    "CABF BR: 7.1"
+);
+pub(super) fn crl_revoked_serial_too_short(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked_serials(crl, LintStatus::Warn, check_too_short)
+}
+
+lint_definition!(
+    CRL_REVOKED_SERIAL_LOW_ENTROPY,
+    "rfc:crl_revoked_serial_low_entropy",
+    "Revoked certificate serial number does not look randomly generated",
+    "CABF BR: 7.1"
+);
+pub(super) fn crl_revoked_serial_low_entropy(crl: &CertificateRevocationList) -> LintResult {
+    check_revoked_serials(crl, LintStatus::Warn, check_low_entropy)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    #[test]
+    fn serial_patterns() {
+        assert!(check_low_entropy(&hex("0a1b2c3d4e5f60718293a4b5c6d7e8f9")).is_ok());
+        assert!(check_low_entropy(&hex("01")).is_ok());
+        assert!(check_low_entropy(&hex("4242424242424242")).is_err());
+        assert!(check_low_entropy(&hex("0102030405060708")).is_err());
+        // 2024-06-01T00:00:00Z, in seconds and milliseconds
+        assert!(check_low_entropy(&hex("665a6480")).is_err());
+        assert!(check_low_entropy(&hex("018fd2f4d600")).is_err());
+        // 20240601123000 in BCD
+        assert!(check_low_entropy(&hex("20240601123000")).is_err());
+        assert!(check_too_short(&hex("7fffffffffffffff")).is_err());
+        assert!(check_too_short(&hex("00ffffffffffffffff")).is_ok());
+    }
+
+    #[test]
+    fn certificate_serial() {
+        for (serial, expected) in [
+            (hex("5f3a9c4e1b7d2806e4a1c3b5d7f90214"), &[][..]),
+            (hex("00"), &["rfc:serial_zero", "rfc:serial_too_short"]),
+            (
+                hex("5f3a9c4e1b7d2806e4a1c3b5d7f902145f3a9c4e1b"),
+                &["rfc:serial_too_long"],
+            ),
+        ] {
+            let data = CertificateBuilder {
+                serial,
+                ..Default::default()
+            }
+            .build();
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            assert_eq!(cert_lint_failures(SERIAL_LINTS, &x509), expected);
+        }
+    }
+
+    #[test]
+    fn revoked_serials() {
+        for (serial, expected) in [
+            (hex("5f3a9c4e1b7d2806e4a1c3b5d7f90214"), &[][..]),
+            (
+                hex("5f3a9c4e1b7d2806e4a1c3b5d7f902145f3a9c4e1b"),
+                &["rfc:crl_revoked_serial_too_long"],
+            ),
+            (
+                hex("00"),
+                &[
+                    "rfc:crl_revoked_serial_zero",
+                    "rfc:crl_revoked_serial_too_short",
+                ],
+            ),
+            (
+                hex("df3a9c4e1b7d2806e4a1c3b5d7f90214"),
+                &["rfc:crl_revoked_serial_negative"],
+            ),
+            (hex("0a1b2c"), &["rfc:crl_revoked_serial_too_short"]),
+            (
+                hex("0102030405060708090a"),
+                &["rfc:crl_revoked_serial_low_entropy"],
+            ),
+        ] {
+            let data = CrlBuilder {
+                revoked: vec![revoked_entry(&serial, &[])],
+                ..Default::default()
+            }
+            .build();
+            let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+            assert_eq!(crl_lint_failures(CRL_SERIAL_LINTS, &crl), expected);
+        }
+    }
+}
@@ -0,0 +1,444 @@
+use x509_parser::asn1_rs::{Oid, Tag};
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+use x509_parser::signature_algorithm::RsaSsaPssParams;
+use x509_parser::x509::AlgorithmIdentifier;
+
+use super::oids::*;
+use crate::*;
+
+pub(crate) const SIGNATURE_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (SIGNATURE_RSA_PARAMS_NOT_NULL, signature_rsa_params_not_null),
+    (
+        SIGNATURE_ECDSA_PARAMS_PRESENT,
+        signature_ecdsa_params_present,
+    ),
+    (
+        SIGNATURE_EDDSA_PARAMS_PRESENT,
+        signature_eddsa_params_present,
+    ),
+    (
+        SIGNATURE_RSA_PSS_PARAMS_INVALID,
+        signature_rsa_pss_params_invalid,
+    ),
+    (
+        SIGNATURE_RSA_PSS_MGF1_HASH_MISMATCH,
+        signature_rsa_pss_mgf1_hash_mismatch,
+    ),
+    (
+        SIGNATURE_RSA_PSS_SALT_LENGTH_MISMATCH,
+        signature_rsa_pss_salt_length_mismatch,
+    ),
+];
+
+pub(crate) const CRL_SIGNATURE_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (
+        CRL_SIGNATURE_RSA_PARAMS_NOT_NULL,
+        crl_signature_rsa_params_not_null,
+    ),
+    (
+        CRL_SIGNATURE_ECDSA_PARAMS_PRESENT,
+        crl_signature_ecdsa_params_present,
+    ),
+    (
+        CRL_SIGNATURE_EDDSA_PARAMS_PRESENT,
+        crl_signature_eddsa_params_present,
+    ),
+    (
+        CRL_SIGNATURE_RSA_PSS_PARAMS_INVALID,
+        crl_signature_rsa_pss_params_invalid,
+    ),
+    (
+        CRL_SIGNATURE_RSA_PSS_MGF1_HASH_MISMATCH,
+        crl_signature_rsa_pss_mgf1_hash_mismatch,
+    ),
+    (
+        CRL_SIGNATURE_RSA_PSS_SALT_LENGTH_MISMATCH,
+        crl_signature_rsa_pss_salt_length_mismatch,
+    ),
+];
+
+/// RSA PKCS#1 v1.5 signature algorithms (RFC3279 and RFC4055)
+const RSA_PKCS1_ALGORITHMS: &[Oid<'static>] = &[
+    OID_PKCS1_MD2WITHRSAENC,
+    OID_PKCS1_MD4WITHRSAENC,
+    OID_PKCS1_MD5WITHRSAENC,
+    OID_PKCS1_SHA1WITHRSA,
+    OID_PKCS1_SHA224WITHRSA,
+    OID_PKCS1_SHA256WITHRSA,
+    OID_PKCS1_SHA384WITHRSA,
+    OID_PKCS1_SHA512WITHRSA,
+];
+
+/// ECDSA signature algorithms (RFC3279 and RFC5758)
+const ECDSA_ALGORITHMS: &[Oid<'static>] = &[
+    OID_SIG_ECDSA_WITH_SHA1,
+    OID_SIG_ECDSA_WITH_SHA224,
+    OID_SIG_ECDSA_WITH_SHA256,
+    OID_SIG_ECDSA_WITH_SHA384,
+    OID_SIG_ECDSA_WITH_SHA512,
+];
+
+/// EdDSA signature algorithms (RFC8410)
+const EDDSA_ALGORITHMS: &[Oid<'static>] = &[OID_SIG_ED25519, OID_SIG_ED448];
+
+/// Hash algorithms allowed in RSASSA-PSS parameters and OCSP CertID, and their output length
+/// (in bytes)
+pub(super) const HASH_LENGTHS: &[(Oid<'static>, u32)] = &[
+    (OID_HASH_SHA1, 20),
+    (OID_NIST_HASH_SHA224, 28),
+    (OID_NIST_HASH_SHA256, 32),
+    (OID_NIST_HASH_SHA384, 48),
+    (OID_NIST_HASH_SHA512, 64),
+];
+
+fn check_rsa_params_null(alg: &AlgorithmIdentifier) -> Result<(), String> {
+    if !RSA_PKCS1_ALGORITHMS.contains(&alg.algorithm) {
+        return Ok(());
+    }
+    match &alg.parameters {
+        Some(p) if p.tag() == Tag::Null && p.data.is_empty() => Ok(()),
+        Some(p) => Err(format!("parameters have tag {}", p.tag())),
+        None => Err("parameters are absent".to_string()),
+    }
+}
+
+fn check_params_absent(alg: &AlgorithmIdentifier, algorithms: &[Oid]) -> Result<(), String> {
+    if algorithms.contains(&alg.algorithm) && alg.parameters.is_some() {
+        return Err(alg.algorithm.to_id_string());
+    }
+    Ok(())
+}
+
+/// Return the RSASSA-PSS parameters, if the algorithm is id-RSASSA-PSS
+///
+/// Parameters are required for id-RSASSA-PSS in signatures (RFC4055 section 3.1).
+pub(super) fn rsa_pss_params<'a>(
+    alg: &'a AlgorithmIdentifier,
+) -> Option<Result<RsaSsaPssParams<'a>, String>> {
+    if alg.algorithm != OID_PKCS1_RSASSAPSS {
+        return None;
+    }
+    let Some(params) = &alg.parameters else {
+        return Some(Err("parameters are absent".to_string()));
+    };
+    Some(RsaSsaPssParams::try_from(params).map_err(|e| e.to_string()))
+}
+
+fn check_rsa_pss_params(alg: &AlgorithmIdentifier) -> Result<(), String> {
+    let params = match rsa_pss_params(alg) {
+        Some(params) => params?,
+        None => return Ok(()),
+    };
+    let hash = params.hash_algorithm_oid();
+    if !HASH_LENGTHS.iter().any(|(oid, _)| oid == hash) {
+        return Err(format!(
+            "unsupported hash algorithm {}",
+            hash.to_id_string()
+        ));
+    }
+    let mgf = params
+        .mask_gen_algorithm()
+        .map_err(|_| "invalid mask generation algorithm".to_string())?;
+    if mgf.mgf != OID_PKCS1_MGF1 {
+        let mgf = mgf.mgf.to_id_string();
+        return Err(format!("unsupported mask generation function {mgf}"));
+    }
+    if params.trailer_field() != 1 {
+        return Err(format!("trailerField is {}", params.trailer_field()));
+    }
+    Ok(())
+}
+
+fn check_rsa_pss_mgf1_hash(alg: &AlgorithmIdentifier) -> Result<(), String> {
+    let Some(Ok(params)) = rsa_pss_params(alg) else {
+        return Ok(());
+    };
+    let Ok(mgf) = params.mask_gen_algorithm() else {
+        return Ok(());
+    };
+    let hash = params.hash_algorithm_oid();
+    if mgf.mgf == OID_PKCS1_MGF1 && mgf.hash != *hash {
+        let (hash, mgf_hash) = (hash.to_id_string(), mgf.hash.to_id_string());
+        return Err(format!("hash {hash}, MGF1 hash {mgf_hash}"));
+    }
+    Ok(())
+}
+
+fn check_rsa_pss_salt_length(alg: &AlgorithmIdentifier) -> Result<(), String> {
+    let Some(Ok(params)) = rsa_pss_params(alg) else {
+        return Ok(());
+    };
+    let hash = params.hash_algorithm_oid();
+    let Some(&(_, len)) = HASH_LENGTHS.iter().find(|(oid, _)| oid == hash) else {
+        return Ok(());
+    };
+    if params.salt_length() != len {
+        let salt_length = params.salt_length();
+        return Err(format!("saltLength {salt_length}, hash length {len}"));
+    }
+    Ok(())
+}
+
+/// Run `check` on both signature algorithm identifiers of the certificate
+fn check_cert_algorithms(
+    x509: &X509Certificate,
+    status: LintStatus,
+    check: impl Fn(&AlgorithmIdentifier) -> Result<(), String>,
+) -> LintResult {
+    let algorithms = [
+        ("signatureAlgorithm", &x509.signature_algorithm),
+        ("tbsCertificate.signature", &x509.tbs_certificate.signature),
+    ];
+    check_algorithms(&algorithms, status, check)
+}
+
+/// Run `check` on both signature algorithm identifiers of the CRL
+fn check_crl_algorithms(
+    crl: &CertificateRevocationList,
+    status: LintStatus,
+    check: impl Fn(&AlgorithmIdentifier) -> Result<(), String>,
+) -> LintResult {
+    let algorithms = [
+        ("signatureAlgorithm", &crl.signature_algorithm),
+        ("tbsCertList.signature", &crl.tbs_cert_list.signature),
+    ];
+    check_algorithms(&algorithms, status, check)
+}
+
+fn check_algorithms(
+    algorithms: &[(&str, &AlgorithmIdentifier)],
+    status: LintStatus,
+    check: impl Fn(&AlgorithmIdentifier) -> Result<(), String>,
+) -> LintResult {
+    for (label, alg) in algorithms {
+        if let Err(e) = check(alg) {
+            let details = LintDetails::new(format!("{label}: {e}"));
+            return LintResult::new_details(status, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    SIGNATURE_RSA_PARAMS_NOT_NULL,
+    "rfc:signature_rsa_params_not_null",
+    "The parameters of RSA PKCS#1 v1.5 signature algorithms MUST be NULL",
+    "RFC4055: 5"
+);
+pub(super) fn signature_rsa_params_not_null(x509: &X509Certificate) -> LintResult {
+    check_cert_algorithms(x509, LintStatus::Error, check_rsa_params_null)
+}
+
+lint_definition!(
+    SIGNATURE_ECDSA_PARAMS_PRESENT,
+    "rfc:signature_ecdsa_params_present",
+    "The parameters of ECDSA signature algorithms MUST be absent",
+    "RFC5758: 3.2"
+);
+pub(super) fn signature_ecdsa_params_present(x509: &X509Certificate) -> LintResult {
+    check_cert_algorithms(x509, LintStatus::Error, |alg| {
+        check_params_absent(alg, ECDSA_ALGORITHMS)
+    })
+}
+
+lint_definition!(
+    SIGNATURE_EDDSA_PARAMS_PRESENT,
+    "rfc:signature_eddsa_params_present",
+    "The parameters of Ed25519 and Ed448 signature algorithms MUST be absent",
+    "RFC8410: 3"
+);
+pub(super) fn signature_eddsa_params_present(x509: &X509Certificate) -> LintResult {
+    check_cert_algorithms(x509, LintStatus::Error, |alg| {
+        check_params_absent(alg, EDDSA_ALGORITHMS)
+    })
+}
+
+lint_definition!(
+    SIGNATURE_RSA_PSS_PARAMS_INVALID,
+    "rfc:signature_rsa_pss_params_invalid",
+    "RSASSA-PSS parameters MUST be present and well-formed",
+    "RFC4055: 3.1"
+);
+pub(super) fn signature_rsa_pss_params_invalid(x509: &X509Certificate) -> LintResult {
+    check_cert_algorithms(x509, LintStatus::Error, check_rsa_pss_params)
+}
+
+lint_definition!(
+    SIGNATURE_RSA_PSS_MGF1_HASH_MISMATCH,
+    "rfc:signature_rsa_pss_mgf1_hash_mismatch",
+    "RSASSA-PSS MGF1 hash algorithm should be the same as the message hash algorithm",
+    "RFC4055: 3.1"
+);
+pub(super) fn signature_rsa_pss_mgf1_hash_mismatch(x509: &X509Certificate) -> LintResult {
+    check_cert_algorithms(x509, LintStatus::Warn, check_rsa_pss_mgf1_hash)
+}
+
+lint_definition!(
+    SIGNATURE_RSA_PSS_SALT_LENGTH_MISMATCH,
+    "rfc:signature_rsa_pss_salt_length_mismatch",
+    "RSASSA-PSS saltLength should be the length of the hash algorithm output",
+    "RFC4055: 3.1"
+);
+pub(super) fn signature_rsa_pss_salt_length_mismatch(x509: &X509Certificate) -> LintResult {
+    check_cert_algorithms(x509, LintStatus::Warn, check_rsa_pss_salt_length)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_RSA_PARAMS_NOT_NULL,
+    "rfc:crl_signature_rsa_params_not_null",
+    "The parameters of RSA PKCS#1 v1.5 signature algorithms MUST be NULL",
+    "RFC4055: 5"
+);
+pub(super) fn crl_signature_rsa_params_not_null(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_algorithms(crl, LintStatus::Error, check_rsa_params_null)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_ECDSA_PARAMS_PRESENT,
+    "rfc:crl_signature_ecdsa_params_present",
+    "The parameters of ECDSA signature algorithms MUST be absent",
+    "RFC5758: 3.2"
+);
+pub(super) fn crl_signature_ecdsa_params_present(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_algorithms(crl, LintStatus::Error, |alg| {
+        check_params_absent(alg, ECDSA_ALGORITHMS)
+    })
+}
+
+lint_definition!(
+    CRL_SIGNATURE_EDDSA_PARAMS_PRESENT,
+    "rfc:crl_signature_eddsa_params_present",
+    "The parameters of Ed25519 and Ed448 signature algorithms MUST be absent",
+    "RFC8410: 3"
+);
+pub(super) fn crl_signature_eddsa_params_present(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_algorithms(crl, LintStatus::Error, |alg| {
+        check_params_absent(alg, EDDSA_ALGORITHMS)
+    })
+}
+
+lint_definition!(
+    CRL_SIGNATURE_RSA_PSS_PARAMS_INVALID,
+    "rfc:crl_signature_rsa_pss_params_invalid",
+    "RSASSA-PSS parameters MUST be present and well-formed",
+    "RFC4055: 3.1"
+);
+pub(super) fn crl_signature_rsa_pss_params_invalid(crl: &CertificateRevocationList) -> LintResult {
+    check_crl_algorithms(crl, LintStatus::Error, check_rsa_pss_params)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_RSA_PSS_MGF1_HASH_MISMATCH,
+    "rfc:crl_signature_rsa_pss_mgf1_hash_mismatch",
+    "RSASSA-PSS MGF1 hash algorithm should be the same as the message hash algorithm",
+    "RFC4055: 3.1"
+);
+pub(super) fn crl_signature_rsa_pss_mgf1_hash_mismatch(
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    check_crl_algorithms(crl, LintStatus::Warn, check_rsa_pss_mgf1_hash)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_RSA_PSS_SALT_LENGTH_MISMATCH,
+    "rfc:crl_signature_rsa_pss_salt_length_mismatch",
+    "RSASSA-PSS saltLength should be the length of the hash algorithm output",
+    "RFC4055: 3.1"
+);
+pub(super) fn crl_signature_rsa_pss_salt_length_mismatch(
+    crl: &CertificateRevocationList,
+) -> LintResult {
+    check_crl_algorithms(crl, LintStatus::Warn, check_rsa_pss_salt_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn hash_algorithm(hash: &Oid) -> Vec<u8> {
+        seq(&[&oid(hash), &der(0x05, &[])])
+    }
+
+    fn rsa_pss(hash: &Oid, mgf1_hash: &Oid, salt_length: u8) -> Vec<u8> {
+        let mgf = seq(&[&oid(&OID_PKCS1_MGF1), &hash_algorithm(mgf1_hash)]);
+        let params = seq(&[
+            &der(0xa0, &hash_algorithm(hash)),
+            &der(0xa1, &mgf),
+            &der(0xa2, &int(&[salt_length])),
+        ]);
+        seq(&[&oid(&OID_PKCS1_RSASSAPSS), &params])
+    }
+
+    /// Signature algorithms, and the name of the lint they trigger (without prefix)
+    fn signature_algorithms() -> Vec<(Vec<u8>, Option<&'static str>)> {
+        let null = der(0x05, &[]);
+        vec![
+            (seq(&[&oid(&OID_PKCS1_SHA256WITHRSA), &null]), None),
+            (
+                seq(&[&oid(&OID_PKCS1_SHA256WITHRSA)]),
+                Some("signature_rsa_params_not_null"),
+            ),
+            (seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256)]), None),
+            (
+                seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256), &null]),
+                Some("signature_ecdsa_params_present"),
+            ),
+            (seq(&[&oid(&OID_SIG_ED25519)]), None),
+            (
+                seq(&[&oid(&OID_SIG_ED25519), &null]),
+                Some("signature_eddsa_params_present"),
+            ),
+            (
+                rsa_pss(&OID_NIST_HASH_SHA256, &OID_NIST_HASH_SHA256, 32),
+                None,
+            ),
+            (
+                seq(&[&oid(&OID_PKCS1_RSASSAPSS)]),
+                Some("signature_rsa_pss_params_invalid"),
+            ),
+            (
+                rsa_pss(&OID_NIST_HASH_SHA256, &OID_HASH_SHA1, 32),
+                Some("signature_rsa_pss_mgf1_hash_mismatch"),
+            ),
+            (
+                rsa_pss(&OID_NIST_HASH_SHA256, &OID_NIST_HASH_SHA256, 20),
+                Some("signature_rsa_pss_salt_length_mismatch"),
+            ),
+        ]
+    }
+
+    #[test]
+    fn certificate_signature_algorithms() {
+        for (signature_algorithm, lint) in signature_algorithms() {
+            let data = CertificateBuilder {
+                signature_algorithm,
+                ..Default::default()
+            }
+            .build();
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            let expected: Vec<_> = lint.map(|lint| format!("rfc:{lint}")).into_iter().collect();
+            assert_eq!(cert_lint_failures(SIGNATURE_LINTS, &x509), expected);
+        }
+    }
+
+    #[test]
+    fn crl_signature_algorithms() {
+        for (signature_algorithm, lint) in signature_algorithms() {
+            let data = CrlBuilder {
+                signature_algorithm,
+                ..Default::default()
+            }
+            .build();
+            let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+            let expected: Vec<_> = lint
+                .map(|lint| format!("rfc:crl_{lint}"))
+                .into_iter()
+                .collect();
+            assert_eq!(crl_lint_failures(CRL_SIGNATURE_LINTS, &crl), expected);
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use x509_parser::asn1_rs::{Any, FromDer, Oid, Tag};
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::X509Certificate;
+use x509_parser::public_key::{PublicKey, RSAPublicKey};
+
+use super::helpers::{bit_length, der_items};
+use super::oids::*;
+use crate::*;
+
+pub(crate) const SPKI_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (SPKI_RSA_PARAMS_NOT_NULL, spki_rsa_params_not_null),
+    (SPKI_RSA_MODULUS_INVALID, spki_rsa_modulus_invalid),
+    (SPKI_RSA_EXPONENT_INVALID, spki_rsa_exponent_invalid),
+    (SPKI_RSA_EXPONENT_NOT_65537, spki_rsa_exponent_not_65537),
+    (SPKI_EC_NOT_NAMED_CURVE, spki_ec_not_named_curve),
+    (SPKI_EC_POINT_COMPRESSED, spki_ec_point_compressed),
+    (SPKI_EC_POINT_INVALID, spki_ec_point_invalid),
+    (SPKI_EDWARDS_PARAMS_PRESENT, spki_edwards_params_present),
+    (SPKI_EDWARDS_KEY_LENGTH, spki_edwards_key_length),
+];
+
+pub(crate) const SPKI_CONFIGURABLE_LINTS: &[(LintDefinition, ConfigurableCertificateLint)] = &[
+    (SPKI_RSA_MODULUS_TOO_SMALL, spki_rsa_modulus_too_small),
+    (SPKI_EC_CURVE_NOT_ALLOWED, spki_ec_curve_not_allowed),
+];
+
+/// Named curves, and the size of their field elements (in bytes)
+const CURVE_FIELD_SIZES: &[(Oid<'static>, usize)] = &[
+    (OID_EC_P192, 24),
+    (OID_EC_P224, 28),
+    (OID_EC_P256, 32),
+    (OID_NIST_EC_P384, 48),
+    (OID_NIST_EC_P521, 66),
+    (OID_EC_SECP256K1, 32),
+    (OID_EC_BRAINPOOL_P256R1, 32),
+    (OID_EC_BRAINPOOL_P384R1, 48),
+    (OID_EC_BRAINPOOL_P512R1, 64),
+];
+
+/// Algorithms from RFC8410, and the length of their public keys (in bytes)
+const EDWARDS_KEY_LENGTHS: &[(Oid<'static>, usize)] = &[
+    (OID_SIG_ED25519, 32),
+    (OID_SIG_ED448, 57),
+    (OID_KEY_TYPE_X25519, 32),
+    (OID_KEY_TYPE_X448, 56),
+];
+
+/// Return the RSA public key, if the SPKI algorithm is rsaEncryption
+///
+/// The parser rejects empty integers, so the key is read from the raw encoding if it fails.
+pub(super) fn rsa_public_key<'a>(x509: &'a X509Certificate) -> Option<RSAPublicKey<'a>> {
+    let spki = x509.public_key();
+    if spki.algorithm.algorithm != OID_PKCS1_RSAENCRYPTION {
+        return None;
+    }
+    if let Ok(PublicKey::RSA(rsa)) = spki.parsed() {
+        return Some(rsa);
+    }
+    let (_, key) = Any::from_der(&spki.subject_public_key.data).ok()?;
+    let mut items = der_items(key.data).filter(|item| item.tag() == Tag::Integer);
+    let (modulus, exponent) = (items.next()?, items.next()?);
+    Some(RSAPublicKey {
+        modulus: modulus.data,
+        exponent: exponent.data,
+    })
+}
+
+/// Return the named curve of an EC public key, if the SPKI algorithm is id-ecPublicKey
+pub(super) fn ec_named_curve<'a>(x509: &'a X509Certificate) -> Option<Option<Oid<'a>>> {
+    let alg = &x509.public_key().algorithm;
+    if alg.algorithm != OID_KEY_TYPE_EC_PUBLIC_KEY {
+        return None;
+    }
+    let curve = alg
+        .parameters
+        .as_ref()
+        .filter(|p| p.tag() == Tag::Oid)
+        .and_then(|p| p.clone().oid().ok());
+    Some(curve)
+}
+
+lint_definition!(
+    SPKI_RSA_PARAMS_NOT_NULL,
+    "rfc:spki_rsa_params_not_null",
+    "The parameters field of rsaEncryption MUST have ASN.1 type NULL",
+    "RFC3279: 2.3.1"
+);
+certificate_lint!(
+    pub(super) spki_rsa_params_not_null,
+    LintStatus::Error,
+    |x509: &X509Certificate| {
+        let alg = &x509.public_key().algorithm;
+        alg.algorithm == OID_PKCS1_RSAENCRYPTION
+            && !alg
+                .parameters
+                .as_ref()
+                .is_some_and(|p| p.tag() == Tag::Null && p.data.is_empty())
+    }
+);
+
+lint_definition!(
+    SPKI_RSA_MODULUS_TOO_SMALL,
+    "rfc:spki_rsa_modulus_too_small",
+    "RSA modulus is smaller than the minimum size",
+    "CABF BR: 6.1.5"
+);
+pub(super) fn spki_rsa_modulus_too_small(
+    x509: &X509Certificate,
+    config: &LintConfig,
+) -> LintResult {
+    let Some(rsa) = rsa_public_key(x509) else {
+        return LintResult::pass();
+    };
+    let bits = bit_length(rsa.modulus);
+    let min_bits = config.rsa_min_modulus_bits;
+    if bits < min_bits {
+        let details = LintDetails::new(format!("{bits} bits (minimum: {min_bits})"));
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    SPKI_RSA_MODULUS_INVALID,
+    "rfc:spki_rsa_modulus_invalid",
+    "RSA modulus MUST be a positive odd integer",
+    "RFC8017: 3.1"
+);
+pub(super) fn spki_rsa_modulus_invalid(x509: &X509Certificate) -> LintResult {
+    let Some(rsa) = rsa_public_key(x509) else {
+        return LintResult::pass();
+    };
+    match rsa.modulus {
+        [] => LintResult::new_details(LintStatus::Error, "empty modulus".into()),
+        [first, ..] if first & 0x80 != 0 => {
+            LintResult::new_details(LintStatus::Error, "negative modulus".into())
+        }
+        [.., last] if last & 1 == 0 => {
+            LintResult::new_details(LintStatus::Error, "even modulus".into())
+        }
+        _ => LintResult::pass(),
+    }
+}
+
+lint_definition!(
+    SPKI_RSA_EXPONENT_INVALID,
+    "rfc:spki_rsa_exponent_invalid",
+    "RSA public exponent MUST be an odd integer greater than or equal to 3",
+    "RFC8017: 3.1"
+);
+pub(super) fn spki_rsa_exponent_invalid(x509: &X509Certificate) -> LintResult {
+    let Some(rsa) = rsa_public_key(x509) else {
+        return LintResult::pass();
+    };
+    let e = rsa.exponent;
+    if e.is_empty() {
+        return LintResult::new_details(LintStatus::Error, "empty exponent".into());
+    }
+    if e[0] & 0x80 != 0 {
+        return LintResult::new_details(LintStatus::Error, "negative exponent".into());
+    }
+    if e[e.len() - 1] & 1 == 0 {
+        return LintResult::new_details(LintStatus::Error, "even exponent".into());
+    }
+    if bit_length(e) < 2 {
+        return LintResult::new_details(LintStatus::Error, "exponent is 1".into());
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    SPKI_RSA_EXPONENT_NOT_65537,
+    "rfc:spki_rsa_exponent_not_65537",
+    "RSA public exponent is not 65537",
+    "CABF BR: 6.1.6"
+);
+pub(super) fn spki_rsa_exponent_not_65537(x509: &X509Certificate) -> LintResult {
+    let Some(rsa) = rsa_public_key(x509) else {
+        return LintResult::pass();
+    };
+    match rsa.try_exponent() {
+        Ok(65537) => LintResult::pass(),
+        Ok(e) => LintResult::new_details(LintStatus::Warn, LintDetails::new(e.to_string())),
+        Err(_) => LintResult::new(LintStatus::Warn),
+    }
+}
+
+lint_definition!(
+    SPKI_EC_NOT_NAMED_CURVE,
+    "rfc:spki_ec_not_named_curve",
+    "EC public key parameters MUST be a namedCurve, implicitCurve and specifiedCurve MUST NOT be used",
+    "RFC5480: 2.1.1"
+);
+certificate_lint!(
+    pub(super) spki_ec_not_named_curve,
+    LintStatus::Error,
+    |x509: &X509Certificate| matches!(ec_named_curve(x509), Some(None))
+);
+
+lint_definition!(
+    SPKI_EC_CURVE_NOT_ALLOWED,
+    "rfc:spki_ec_curve_not_allowed",
+    "EC public key uses a curve which is not allowed",
+    "CABF BR: 6.1.5"
+);
+pub(super) fn spki_ec_curve_not_allowed(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    let Some(Some(curve)) = ec_named_curve(x509) else {
+        return LintResult::pass();
+    };
+    if config.allowed_ec_curves.contains(&curve) {
+        return LintResult::pass();
+    }
+    LintResult::new_details(LintStatus::Error, LintDetails::new(curve.to_id_string()))
+}
+
+lint_definition!(
+    SPKI_EC_POINT_COMPRESSED,
+    "rfc:spki_ec_point_compressed",
+    "EC public key uses the compressed form",
+    "RFC5480: 2.2"
+);
+certificate_lint!(
+    pub(super) spki_ec_point_compressed,
+    LintStatus::Warn,
+    |x509: &X509Certificate| ec_named_curve(x509).is_some()
+        && matches!(x509.public_key().subject_public_key.data.first(), Some(2 | 3))
+);
+
+lint_definition!(
+    SPKI_EC_POINT_INVALID,
+    "rfc:spki_ec_point_invalid",
+    "Invalid EC point encoding or length",
+    "RFC5480: 2.2"
+);
+pub(super) fn spki_ec_point_invalid(x509: &X509Certificate) -> LintResult {
+    let Some(Some(curve)) = ec_named_curve(x509) else {
+        return LintResult::pass();
+    };
+    let point = x509.public_key().subject_public_key.data.as_ref();
+    let Some(&(_, size)) = CURVE_FIELD_SIZES.iter().find(|(oid, _)| *oid == curve) else {
+        return LintResult::pass();
+    };
+    let expected = match point.first() {
+        Some(4) => 1 + 2 * size,
+        Some(2 | 3) => 1 + size,
+        _ => {
+            return LintResult::new_details(LintStatus::Error, "invalid point prefix".into());
+        }
+    };
+    if point.len() != expected {
+        let details = LintDetails::new(format!("{} bytes, expected {expected}", point.len()));
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    SPKI_EDWARDS_PARAMS_PRESENT,
+    "rfc:spki_edwards_params_present",
+    "For Ed25519, Ed448, X25519 and X448 keys, the parameters MUST be absent",
+    "RFC8410: 3"
+);
+certificate_lint!(
+    pub(super) spki_edwards_params_present,
+    LintStatus::Error,
+    |x509: &X509Certificate| {
+        let alg = &x509.public_key().algorithm;
+        alg.parameters.is_some()
+            && EDWARDS_KEY_LENGTHS
+                .iter()
+                .any(|(oid, _)| *oid == alg.algorithm)
+    }
+);
+
+lint_definition!(
+    SPKI_EDWARDS_KEY_LENGTH,
+    "rfc:spki_edwards_key_length",
+    "Invalid public key length for Ed25519, Ed448, X25519 or X448",
+    "RFC8410: 4"
+);
+pub(super) fn spki_edwards_key_length(x509: &X509Certificate) -> LintResult {
+    let spki = x509.public_key();
+    let Some(&(_, len)) = EDWARDS_KEY_LENGTHS
+        .iter()
+        .find(|(oid, _)| *oid == spki.algorithm.algorithm)
+    else {
+        return LintResult::pass();
+    };
+    let key = spki.subject_public_key.data.as_ref();
+    if key.len() != len {
+        let details = LintDetails::new(format!("{} bytes, expected {len}", key.len()));
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rfc::testing::*;
+
+    /// Certificate with a RSA public key
+    fn rsa_certificate(modulus: &[u8], exponent: &[u8]) -> Vec<u8> {
+        spki_certificate(rsa_spki(modulus, exponent))
+    }
+
+    fn spki_certificate(spki: Vec<u8>) -> Vec<u8> {
+        CertificateBuilder {
+            spki,
+            ..Default::default()
+        }
+        .build()
+    }
+
+    /// Return the names of all SPKI lints not returning `Pass`
+    fn failures(data: &[u8], config: &LintConfig) -> Vec<String> {
+        let (_, x509) = X509Certificate::from_der(data).unwrap();
+        let mut failures = cert_lint_failures(SPKI_LINTS, &x509);
+        failures.extend(cert_configurable_lint_failures(
+            SPKI_CONFIGURABLE_LINTS,
+            &x509,
+            config,
+        ));
+        failures
+    }
+
+    /// Uncompressed point, with the given field size
+    fn uncompressed_point(size: usize) -> Vec<u8> {
+        [&[0x04][..], &vec![0x5b; 2 * size]].concat()
+    }
+
+    #[test]
+    fn rsa_exponent() {
+        let modulus = [&[0x00, 0xc1][..], &[0x5b; 255], &[0x01]].concat();
+        let data = rsa_certificate(&modulus, &[0x01, 0x00, 0x01]);
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(SPKI_LINTS, &x509).is_empty());
+
+        for (exponent, details) in [
+            (&[][..], "empty exponent"),
+            (&[0x80, 0x01], "negative exponent"),
+            (&[0x01, 0x00], "even exponent"),
+            (&[0x01], "exponent is 1"),
+        ] {
+            let data = rsa_certificate(&modulus, exponent);
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            let result = spki_rsa_exponent_invalid(&x509);
+            assert_eq!(result.status, LintStatus::Error);
+            assert_eq!(result.details.unwrap().to_string(), details);
+        }
+    }
+
+    #[test]
+    fn rsa_modulus() {
+        let config = LintConfig::default();
+        let modulus = [&[0x00, 0xc1][..], &[0x5b; 255], &[0x01]].concat();
+        let data = rsa_certificate(&modulus, &[0x01, 0x00, 0x01]);
+        assert!(failures(&data, &config).is_empty());
+        let config_3072 = LintConfig {
+            rsa_min_modulus_bits: 3072,
+            ..Default::default()
+        };
+        assert_eq!(
+            failures(&data, &config_3072),
+            ["rfc:spki_rsa_modulus_too_small"]
+        );
+
+        let even = [&[0x00, 0xc1][..], &[0x5a; 256]].concat();
+        let data = rsa_certificate(&even, &[0x01, 0x00, 0x01]);
+        assert_eq!(failures(&data, &config), ["rfc:spki_rsa_modulus_invalid"]);
+
+        let data = rsa_certificate(&modulus, &[0x03]);
+        assert_eq!(
+            failures(&data, &config),
+            ["rfc:spki_rsa_exponent_not_65537"]
+        );
+
+        let algorithm = seq(&[&oid(&OID_PKCS1_RSAENCRYPTION)]);
+        let key = seq(&[&int(&modulus), &int(&[0x01, 0x00, 0x01])]);
+        let data = spki_certificate(seq(&[&algorithm, &bits(&key)]));
+        assert_eq!(failures(&data, &config), ["rfc:spki_rsa_params_not_null"]);
+    }
+
+    #[test]
+    fn ec_curve() {
+        let config = LintConfig::default();
+        let data = spki_certificate(ec_spki(&OID_EC_P256, &uncompressed_point(32)));
+        assert!(failures(&data, &config).is_empty());
+
+        let data = spki_certificate(ec_spki(&OID_EC_SECP256K1, &uncompressed_point(32)));
+        assert_eq!(failures(&data, &config), ["rfc:spki_ec_curve_not_allowed"]);
+        let config_secp256k1 = LintConfig {
+            allowed_ec_curves: vec![OID_EC_SECP256K1],
+            ..Default::default()
+        };
+        assert!(failures(&data, &config_secp256k1).is_empty());
+
+        // specifiedCurve, only the version of ECParameters is relevant here
+        let algorithm = seq(&[&oid(&OID_KEY_TYPE_EC_PUBLIC_KEY), &seq(&[&int(&[1])])]);
+        let data = spki_certificate(seq(&[&algorithm, &bits(&uncompressed_point(32))]));
+        assert_eq!(failures(&data, &config), ["rfc:spki_ec_not_named_curve"]);
+    }
+
+    #[test]
+    fn ec_point() {
+        let config = LintConfig::default();
+        let compressed = [&[0x02][..], &[0x5b; 32]].concat();
+        let data = spki_certificate(ec_spki(&OID_EC_P256, &compressed));
+        assert_eq!(failures(&data, &config), ["rfc:spki_ec_point_compressed"]);
+
+        for (point, details) in [
+            (uncompressed_point(48), "97 bytes, expected 65"),
+            ([&[0x03][..], &[0x5b; 48]].concat(), "49 bytes, expected 33"),
+            ([&[0x05][..], &[0x5b; 64]].concat(), "invalid point prefix"),
+        ] {
+            let data = spki_certificate(ec_spki(&OID_EC_P256, &point));
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            let result = spki_ec_point_invalid(&x509);
+            assert_eq!(result.status, LintStatus::Error);
+            assert_eq!(result.details.unwrap().to_string(), details);
+        }
+    }
+
+    #[test]
+    fn edwards_keys() {
+        let config = LintConfig::default();
+        for (algorithm, len) in [(OID_SIG_ED25519, 32), (OID_SIG_ED448, 57)] {
+            let data = spki_certificate(seq(&[&seq(&[&oid(&algorithm)]), &bits(&vec![0x5b; len])]));
+            assert!(failures(&data, &config).is_empty());
+
+            let with_params = seq(&[&oid(&algorithm), &der(0x05, &[])]);
+            let data = spki_certificate(seq(&[&with_params, &bits(&vec![0x5b; len])]));
+            assert_eq!(
+                failures(&data, &config),
+                ["rfc:spki_edwards_params_present"]
+            );
+
+            let data = spki_certificate(seq(&[
+                &seq(&[&oid(&algorithm)]),
+                &bits(&vec![0x5b; len - 1]),
+            ]));
+            assert_eq!(failures(&data, &config), ["rfc:spki_edwards_key_length"]);
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! Builders for DER-encoded certificates, CRLs and OCSP responses, used by lint tests
+//!
+//! Signatures are not verified by lints, so built objects have a dummy signature value.
+
+use x509_parser::asn1_rs::Oid;
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+
+use crate::*;
+
+/// P-256 public key (SubjectPublicKeyInfo)
+const P256_SPKI: &str = "3059301306072a8648ce3d020106082a8648ce3d03010703420004126564f8ef117d101c366bbf2f50cdc5f860935d51d211c73e890ffb7723a828c485a5eb17af21bdee573c82861237f1355180606bc2cf7b829f10ed77beec82";
+
+/// Decode a hexadecimal string
+pub(super) fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Encode a DER object from its identifier octet and content
+pub(crate) fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut v = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        v.push(len as u8);
+    } else {
+        let len_bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|&b| b == 0)
+            .collect();
+        v.push(0x80 | len_bytes.len() as u8);
+        v.extend(len_bytes);
+    }
+    v.extend_from_slice(content);
+    v
+}
+
+pub(super) fn seq(items: &[&[u8]]) -> Vec<u8> {
+    der(0x30, &items.concat())
+}
+
+pub(super) fn set(items: &[&[u8]]) -> Vec<u8> {
+    der(0x31, &items.concat())
+}
+
+pub(super) fn oid(oid: &Oid) -> Vec<u8> {
+    der(0x06, oid.as_bytes())
+}
+
+pub(super) fn int(value: &[u8]) -> Vec<u8> {
+    der(0x02, value)
+}
+
+pub(super) fn boolean(value: bool) -> Vec<u8> {
+    der(0x01, &[if value { 0xff } else { 0 }])
+}
+
+pub(super) fn octets(value: &[u8]) -> Vec<u8> {
+    der(0x04, value)
+}
+
+pub(super) fn bits(value: &[u8]) -> Vec<u8> {
+    der(0x03, &[&[0], value].concat())
+}
+
+/// Name containing a single commonName attribute (UTF8String)
+pub(super) fn name(cn: &str) -> Vec<u8> {
+    let attribute = seq(&[&oid(&OID_X509_COMMON_NAME), &der(0x0c, cn.as_bytes())]);
+    seq(&[&set(&[&attribute])])
+}
+
+pub(super) fn extension(ext_oid: &Oid, critical: bool, value: &[u8]) -> Vec<u8> {
+    if critical {
+        seq(&[&oid(ext_oid), &boolean(true), &octets(value)])
+    } else {
+        seq(&[&oid(ext_oid), &octets(value)])
+    }
+}
+
+/// RSA SubjectPublicKeyInfo
+pub(super) fn rsa_spki(modulus: &[u8], exponent: &[u8]) -> Vec<u8> {
+    let algorithm = seq(&[&oid(&OID_PKCS1_RSAENCRYPTION), &der(0x05, &[])]);
+    let key = seq(&[&int(modulus), &int(exponent)]);
+    seq(&[&algorithm, &bits(&key)])
+}
+
+/// EC SubjectPublicKeyInfo, with a named curve
+pub(super) fn ec_spki(curve: &Oid, point: &[u8]) -> Vec<u8> {
+    let algorithm = seq(&[&oid(&OID_KEY_TYPE_EC_PUBLIC_KEY), &oid(curve)]);
+    seq(&[&algorithm, &bits(point)])
+}
+
+/// Critical BasicConstraints extension
+pub(super) fn basic_constraints(ca: bool) -> Vec<u8> {
+    let value = if ca { seq(&[&boolean(true)]) } else { seq(&[]) };
+    extension(&OID_X509_EXT_BASIC_CONSTRAINTS, true, &value)
+}
+
+/// Certificate built from its main fields
+///
+/// The default is a certificate for `www.example.com` with a P-256 key, issued by `Test CA`.
+pub(super) struct CertificateBuilder {
+    pub(super) serial: Vec<u8>,
+    /// Encoded AlgorithmIdentifier, used for both the `signature` and `signatureAlgorithm` fields
+    pub(super) signature_algorithm: Vec<u8>,
+    pub(super) issuer: Vec<u8>,
+    pub(super) validity: Vec<u8>,
+    pub(super) subject: Vec<u8>,
+    pub(super) spki: Vec<u8>,
+    pub(super) extensions: Vec<Vec<u8>>,
+}
+
+impl Default for CertificateBuilder {
+    fn default() -> Self {
+        CertificateBuilder {
+            serial: hex("5f3a9c4e1b7d2806e4a1c3b5d7f90214"),
+            signature_algorithm: seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256)]),
+            issuer: name("Test CA"),
+            validity: seq(&[&der(0x17, b"250101000000Z"), &der(0x17, b"260101000000Z")]),
+            subject: name("www.example.com"),
+            spki: hex(P256_SPKI),
+            extensions: Vec::new(),
+        }
+    }
+}
+
+impl CertificateBuilder {
+    pub(super) fn with_extensions(extensions: Vec<Vec<u8>>) -> Self {
+        CertificateBuilder {
+            extensions,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn build(&self) -> Vec<u8> {
+        let signature_algorithm = self.signature_algorithm.clone();
+        let mut tbs = [
+            der(0xa0, &int(&[2])),
+            int(&self.serial),
+            signature_algorithm.clone(),
+            self.issuer.clone(),
+            self.validity.clone(),
+            self.subject.clone(),
+            self.spki.clone(),
+        ]
+        .concat();
+        if !self.extensions.is_empty() {
+            tbs.extend(der(0xa3, &der(0x30, &self.extensions.concat())));
+        }
+        seq(&[&der(0x30, &tbs), &signature_algorithm, &bits(&[0; 8])])
+    }
+}
+
+/// CRL built from its main fields
+///
+/// The default is an empty CRL issued by `Test CA`, with a validity of 7 days.
+pub(super) struct CrlBuilder {
+    /// Version (absent for v1 CRLs)
+    pub(super) version: Option<u8>,
+    /// Encoded AlgorithmIdentifier, used for both the `signature` and `signatureAlgorithm` fields
+    pub(super) signature_algorithm: Vec<u8>,
+    pub(super) issuer: Vec<u8>,
+    pub(super) this_update: Vec<u8>,
+    pub(super) next_update: Option<Vec<u8>>,
+    pub(super) revoked: Vec<Vec<u8>>,
+    pub(super) extensions: Vec<Vec<u8>>,
+}
+
+impl Default for CrlBuilder {
+    fn default() -> Self {
+        CrlBuilder {
+            version: Some(1),
+            signature_algorithm: seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256)]),
+            issuer: name("Test CA"),
+            this_update: der(0x17, b"250101000000Z"),
+            next_update: Some(der(0x17, b"250108000000Z")),
+            revoked: Vec::new(),
+            extensions: Vec::new(),
+        }
+    }
+}
+
+impl CrlBuilder {
+    pub(super) fn with_extensions(extensions: Vec<Vec<u8>>) -> Self {
+        CrlBuilder {
+            extensions,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn build(&self) -> Vec<u8> {
+        let signature_algorithm = self.signature_algorithm.clone();
+        let mut tbs = [
+            self.version
+                .map(|version| int(&[version]))
+                .unwrap_or_default(),
+            signature_algorithm.clone(),
+            self.issuer.clone(),
+            self.this_update.clone(),
+        ]
+        .concat();
+        if let Some(next_update) = &self.next_update {
+            tbs.extend_from_slice(next_update);
+        }
+        if !self.revoked.is_empty() {
+            tbs.extend(der(0x30, &self.revoked.concat()));
+        }
+        if !self.extensions.is_empty() {
+            tbs.extend(der(0xa0, &der(0x30, &self.extensions.concat())));
+        }
+        seq(&[&der(0x30, &tbs), &signature_algorithm, &bits(&[0; 8])])
+    }
+}
+
+/// Basic OCSP response built from its main fields
+///
+/// The default is a response signed by `Test CA` (byName), with a single `good` status valid
+/// for one day, and no certificates.
+pub(super) struct OcspResponseBuilder {
+    /// Value of the `responseStatus` ENUMERATED
+    pub(super) response_status: u8,
+    /// Type of the `responseBytes` (absent if `None`)
+    pub(super) response_type: Option<Oid<'static>>,
+    /// Encoded ResponderID (`[1]` byName or `[2]` byKey)
+    pub(super) responder_id: Vec<u8>,
+    pub(super) produced_at: Vec<u8>,
+    /// Encoded AlgorithmIdentifier of the CertID hash
+    pub(super) hash_algorithm: Vec<u8>,
+    pub(super) this_update: Vec<u8>,
+    pub(super) next_update: Option<Vec<u8>>,
+    pub(super) extensions: Vec<Vec<u8>>,
+    pub(super) single_extensions: Vec<Vec<u8>>,
+    pub(super) certs: Vec<Vec<u8>>,
+}
+
+impl Default for OcspResponseBuilder {
+    fn default() -> Self {
+        OcspResponseBuilder {
+            response_status: 0,
+            response_type: Some(OID_PKIX_OCSP_BASIC),
+            responder_id: der(0xa1, &name("Test CA")),
+            produced_at: der(0x18, b"20250101000000Z"),
+            hash_algorithm: seq(&[&oid(&OID_HASH_SHA1), &der(0x05, &[])]),
+            this_update: der(0x18, b"20250101000000Z"),
+            next_update: Some(der(0x18, b"20250102000000Z")),
+            extensions: Vec::new(),
+            single_extensions: Vec::new(),
+            certs: Vec::new(),
+        }
+    }
+}
+
+impl OcspResponseBuilder {
+    pub(super) fn build(&self) -> Vec<u8> {
+        let cert_id = seq(&[
+            &self.hash_algorithm,
+            &octets(&[0; 20]),
+            &octets(&[0; 20]),
+            &int(&[1]),
+        ]);
+        let mut single = [cert_id, der(0x80, &[]), self.this_update.clone()].concat();
+        if let Some(next_update) = &self.next_update {
+            single.extend(der(0xa0, next_update));
+        }
+        if !self.single_extensions.is_empty() {
+            single.extend(der(0xa1, &der(0x30, &self.single_extensions.concat())));
+        }
+        let mut tbs = [
+            self.responder_id.clone(),
+            self.produced_at.clone(),
+            seq(&[&der(0x30, &single)]),
+        ]
+        .concat();
+        if !self.extensions.is_empty() {
+            tbs.extend(der(0xa1, &der(0x30, &self.extensions.concat())));
+        }
+        let signature_algorithm = seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA256)]);
+        let mut basic = [der(0x30, &tbs), signature_algorithm, bits(&[0; 8])].concat();
+        if !self.certs.is_empty() {
+            basic.extend(der(0xa0, &der(0x30, &self.certs.concat())));
+        }
+        let mut response = der(0x0a, &[self.response_status]);
+        if let Some(response_type) = &self.response_type {
+            let response_bytes = seq(&[&oid(response_type), &octets(&der(0x30, &basic))]);
+            response.extend(der(0xa0, &response_bytes));
+        }
+        der(0x30, &response)
+    }
+}
+
+/// Entry of the list of revoked certificates, revoked on 2024-12-15
+pub(super) fn revoked_entry(serial: &[u8], extensions: &[Vec<u8>]) -> Vec<u8> {
+    let mut entry = [int(serial), der(0x17, b"241215000000Z")].concat();
+    if !extensions.is_empty() {
+        entry.extend(der(0x30, &extensions.concat()));
+    }
+    der(0x30, &entry)
+}
+
+/// Return the names of the lints not returning `Pass` for the certificate
+pub(super) fn cert_lint_failures(
+    lints: &[(LintDefinition<'static>, CertificateLint)],
+    x509: &X509Certificate,
+) -> Vec<String> {
+    lints
+        .iter()
+        .filter(|(_, lint)| lint(x509).status != LintStatus::Pass)
+        .map(|(definition, _)| definition.name().to_string())
+        .collect()
+}
+
+/// Return the names of the configurable lints not returning `Pass` for the certificate
+pub(super) fn cert_configurable_lint_failures(
+    lints: &[(LintDefinition<'static>, ConfigurableCertificateLint)],
+    x509: &X509Certificate,
+    config: &LintConfig,
+) -> Vec<String> {
+    lints
+        .iter()
+        .filter(|(_, lint)| lint(x509, config).status != LintStatus::Pass)
+        .map(|(definition, _)| definition.name().to_string())
+        .collect()
+}
+
+/// Return the names of the lints not returning `Pass` for the CRL
+pub(super) fn crl_lint_failures(
+    lints: &[(LintDefinition<'static>, CRLLint)],
+    crl: &CertificateRevocationList,
+) -> Vec<String> {
+    lints
+        .iter()
+        .filter(|(_, lint)| lint(crl).status != LintStatus::Pass)
+        .map(|(definition, _)| definition.name().to_string())
+        .collect()
+}
+
+/// Return the names of the configurable lints not returning `Pass` for the CRL
+pub(super) fn crl_configurable_lint_failures(
+    lints: &[(LintDefinition<'static>, ConfigurableCRLLint)],
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> Vec<String> {
+    lints
+        .iter()
+        .filter(|(_, lint)| lint(crl, config).status != LintStatus::Pass)
+        .map(|(definition, _)| definition.name().to_string())
+        .collect()
+}
+
+/// Return the names of the lints not returning `Pass` for the certificate and the CRL
+pub(super) fn cert_crl_lint_failures(
+    lints: &[(LintDefinition<'static>, CertificateCRLLint)],
+    x509: &X509Certificate,
+    crl: &CertificateRevocationList,
+) -> Vec<String> {
+    lints
+        .iter()
+        .filter(|(_, lint)| lint(x509, crl).status != LintStatus::Pass)
+        .map(|(definition, _)| definition.name().to_string())
+        .collect()
+}
+
+/// Return the names of the lints not returning `Pass` for the OCSP response
+pub(super) fn ocsp_lint_failures(
+    lints: &[(LintDefinition<'static>, OcspLint)],
+    response: &OcspResponse,
+) -> Vec<String> {
+    lints
+        .iter()
+        .filter(|(_, lint)| lint(response).status != LintStatus::Pass)
+        .map(|(definition, _)| definition.name().to_string())
+        .collect()
+}
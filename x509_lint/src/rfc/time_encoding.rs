@@ -0,0 +1,297 @@
+use x509_parser::asn1_rs::{Any, Class, FromDer, Tag};
+use x509_parser::oid_registry::OID_X509_EXT_INVALIDITY_DATE;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+
+use super::helpers::der_items;
+use crate::*;
+
+pub(crate) const TIME_ENCODING_LINTS: &[(LintDefinition, CertificateLint)] = &[
+    (TIME_MISSING_SECONDS, time_missing_seconds),
+    (TIME_NOT_ZULU, time_not_zulu),
+    (TIME_FRACTIONAL_SECONDS, time_fractional_seconds),
+];
+
+pub(crate) const CRL_TIME_ENCODING_LINTS: &[(LintDefinition, CRLLint)] = &[
+    (CRL_TIME_MISSING_SECONDS, crl_time_missing_seconds),
+    (CRL_TIME_NOT_ZULU, crl_time_not_zulu),
+    (CRL_TIME_FRACTIONAL_SECONDS, crl_time_fractional_seconds),
+];
+
+/// Deviation from the time formats required by RFC5280 (4.1.2.5.1 and 4.1.2.5.2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeFormatError {
+    MissingSeconds,
+    NotZulu,
+    FractionalSeconds,
+}
+
+/// Check the raw encoding of a UTCTime (`YYMMDDHHMMSSZ`) or GeneralizedTime (`YYYYMMDDHHMMSSZ`)
+///
+/// `x509_parser` rejects certificates and CRLs if validity, thisUpdate, nextUpdate or
+/// revocationDate values have no `Z` and no offset, a GeneralizedTime offset, a comma as
+/// decimal sign, or a UTCTime fraction. For these fields, only the other deviations (UTCTime
+/// offsets, missing seconds, GeneralizedTime fractions) can be reported. The invalidityDate
+/// extension is not checked by the parser, so all deviations can be reported.
+fn time_format_errors(t: &Any) -> Vec<TimeFormatError> {
+    let digits = match t.tag() {
+        Tag::UtcTime => 12,
+        Tag::GeneralizedTime => 14,
+        _ => return Vec::new(),
+    };
+    let data = t.data;
+    let mut errors = Vec::new();
+    let (body, zulu) = match data.strip_suffix(b"Z") {
+        Some(body) => (body, true),
+        None => (data, false),
+    };
+    // strip a local time offset, if any
+    let (body, offset) = match body.iter().position(|&b| b == b'+' || b == b'-') {
+        Some(idx) => (&body[..idx], true),
+        None => (body, false),
+    };
+    if !zulu || offset {
+        errors.push(TimeFormatError::NotZulu);
+    }
+    let (body, fraction) = match body.iter().position(|&b| b == b'.' || b == b',') {
+        Some(idx) => (&body[..idx], true),
+        None => (body, false),
+    };
+    if fraction {
+        errors.push(TimeFormatError::FractionalSeconds);
+    }
+    if body.len() < digits {
+        errors.push(TimeFormatError::MissingSeconds);
+    }
+    errors
+}
+
+/// Return the validity times of the certificate, read from the raw encoding
+fn cert_times<'a>(x509: &'a X509Certificate) -> Vec<(String, Any<'a>)> {
+    let Ok((_, tbs)) = Any::from_der(x509.tbs_certificate.as_ref()) else {
+        return Vec::new();
+    };
+    // skip optional version, serialNumber, signature and issuer
+    let validity = der_items(tbs.data)
+        .skip_while(|item| item.class() == Class::ContextSpecific)
+        .nth(3);
+    let labels = ["notBefore", "notAfter"];
+    validity
+        .map(|v| {
+            let times = der_items(v.data).collect::<Vec<_>>();
+            labels.iter().map(|l| l.to_string()).zip(times).collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Return all times of the CRL, read from the raw encoding
+fn crl_times<'a>(crl: &'a CertificateRevocationList) -> Vec<(String, Any<'a>)> {
+    let mut times = Vec::new();
+    let Ok((_, tbs)) = Any::from_der(crl.tbs_cert_list.as_ref()) else {
+        return times;
+    };
+    // skip optional version, signature and issuer
+    let mut items = der_items(tbs.data)
+        .skip_while(|item| item.tag() == Tag::Integer)
+        .skip(2)
+        .peekable();
+    if let Some(this_update) = items.next() {
+        times.push(("thisUpdate".to_string(), this_update));
+    }
+    if let Some(next_update) =
+        items.next_if(|item| matches!(item.tag(), Tag::UtcTime | Tag::GeneralizedTime))
+    {
+        times.push(("nextUpdate".to_string(), next_update));
+    }
+    if let Some(revoked) = items.next_if(|item| item.tag() == Tag::Sequence) {
+        for entry in der_items(revoked.data) {
+            let mut fields = der_items(entry.data);
+            let serial = fields.next();
+            if let Some(date) = fields.next() {
+                let serial = serial.map(|s| format_serial(s.data)).unwrap_or_default();
+                times.push((format!("revocationDate ({serial})"), date));
+            }
+        }
+    }
+    for revoked in crl.iter_revoked_certificates() {
+        for ext in revoked.iter_extensions() {
+            if ext.oid != OID_X509_EXT_INVALIDITY_DATE {
+                continue;
+            }
+            if let Ok((_, date)) = Any::from_der(ext.value) {
+                let label = format!("invalidityDate ({})", revoked.raw_serial_as_string());
+                times.push((label, date));
+            }
+        }
+    }
+    times
+}
+
+fn format_serial(data: &[u8]) -> String {
+    data.iter()
+        .map(|b| format!("{b:02x}"))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+fn check_times(times: Vec<(String, Any)>, error: TimeFormatError) -> LintResult {
+    for (label, t) in times {
+        if time_format_errors(&t).contains(&error) {
+            let value = String::from_utf8_lossy(t.data);
+            let details = LintDetails::new(format!("{label}: {value}"));
+            return LintResult::new_details(LintStatus::Error, details);
+        }
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    TIME_MISSING_SECONDS,
+    "rfc:time_missing_seconds",
+    "UTCTime and GeneralizedTime values MUST include seconds",
+    "RFC5280: 4.1.2.5"
+);
+pub(super) fn time_missing_seconds(x509: &X509Certificate) -> LintResult {
+    check_times(cert_times(x509), TimeFormatError::MissingSeconds)
+}
+
+lint_definition!(
+    TIME_NOT_ZULU,
+    "rfc:time_not_zulu",
+    "UTCTime and GeneralizedTime values MUST be expressed in Greenwich Mean Time (Zulu)",
+    "RFC5280: 4.1.2.5"
+);
+pub(super) fn time_not_zulu(x509: &X509Certificate) -> LintResult {
+    check_times(cert_times(x509), TimeFormatError::NotZulu)
+}
+
+lint_definition!(
+    TIME_FRACTIONAL_SECONDS,
+    "rfc:time_fractional_seconds",
+    "GeneralizedTime values MUST NOT include fractional seconds",
+    "RFC5280: 4.1.2.5.2"
+);
+pub(super) fn time_fractional_seconds(x509: &X509Certificate) -> LintResult {
+    check_times(cert_times(x509), TimeFormatError::FractionalSeconds)
+}
+
+lint_definition!(
+    CRL_TIME_MISSING_SECONDS,
+    "rfc:crl_time_missing_seconds",
+    "UTCTime and GeneralizedTime values MUST include seconds",
+    "RFC5280: 5.1.2.4"
+);
+pub(super) fn crl_time_missing_seconds(crl: &CertificateRevocationList) -> LintResult {
+    check_times(crl_times(crl), TimeFormatError::MissingSeconds)
+}
+
+lint_definition!(
+    CRL_TIME_NOT_ZULU,
+    "rfc:crl_time_not_zulu",
+    "UTCTime and GeneralizedTime values MUST be expressed in Greenwich Mean Time (Zulu)",
+    "RFC5280: 5.1.2.4"
+);
+pub(super) fn crl_time_not_zulu(crl: &CertificateRevocationList) -> LintResult {
+    check_times(crl_times(crl), TimeFormatError::NotZulu)
+}
+
+lint_definition!(
+    CRL_TIME_FRACTIONAL_SECONDS,
+    "rfc:crl_time_fractional_seconds",
+    "GeneralizedTime values MUST NOT include fractional seconds",
+    "RFC5280: 5.1.2.4"
+);
+pub(super) fn crl_time_fractional_seconds(crl: &CertificateRevocationList) -> LintResult {
+    check_times(crl_times(crl), TimeFormatError::FractionalSeconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::testing::*;
+
+    fn errors(tag: Tag, s: &str) -> Vec<TimeFormatError> {
+        let t = Any::from_tag_and_data(tag, s.as_bytes());
+        time_format_errors(&t)
+    }
+
+    #[test]
+    fn time_formats() {
+        use TimeFormatError::*;
+        assert!(errors(Tag::UtcTime, "250101120000Z").is_empty());
+        assert!(errors(Tag::GeneralizedTime, "20500101120000Z").is_empty());
+        assert_eq!(errors(Tag::UtcTime, "2501011200Z"), [MissingSeconds]);
+        assert_eq!(errors(Tag::UtcTime, "250101120000+0100"), [NotZulu]);
+        assert_eq!(errors(Tag::UtcTime, "250101120000"), [NotZulu]);
+        assert_eq!(
+            errors(Tag::GeneralizedTime, "20500101120000.5Z"),
+            [FractionalSeconds]
+        );
+        assert_eq!(
+            errors(Tag::GeneralizedTime, "205001011200-0500"),
+            [NotZulu, MissingSeconds]
+        );
+    }
+
+    fn cert_with_validity(not_before: &[u8], not_after: &[u8]) -> Vec<u8> {
+        CertificateBuilder {
+            validity: seq(&[not_before, not_after]),
+            ..Default::default()
+        }
+        .build()
+    }
+
+    #[test]
+    fn time_encoding_lints() {
+        let data = CertificateBuilder::default().build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert!(cert_lint_failures(TIME_ENCODING_LINTS, &x509).is_empty());
+
+        let data = cert_with_validity(&der(0x17, b"2501011200Z"), &der(0x17, b"260101000000Z"));
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(TIME_ENCODING_LINTS, &x509),
+            ["rfc:time_missing_seconds"]
+        );
+        let data = cert_with_validity(
+            &der(0x17, b"250101120000+0100"),
+            &der(0x18, b"20500101000000.5Z"),
+        );
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        assert_eq!(
+            cert_lint_failures(TIME_ENCODING_LINTS, &x509),
+            ["rfc:time_not_zulu", "rfc:time_fractional_seconds"]
+        );
+
+        let data = CrlBuilder::default().build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert!(crl_lint_failures(CRL_TIME_ENCODING_LINTS, &crl).is_empty());
+
+        // invalidityDate is not checked by the parser
+        let invalidity_date = der(0x18, b"20241215120000,5+0100");
+        let entry = revoked_entry(
+            &[1],
+            &[extension(
+                &OID_X509_EXT_INVALIDITY_DATE,
+                false,
+                &invalidity_date,
+            )],
+        );
+        let data = CrlBuilder {
+            this_update: der(0x18, b"202501010000Z"),
+            revoked: vec![entry],
+            ..Default::default()
+        }
+        .build();
+        let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+        assert_eq!(
+            crl_lint_failures(CRL_TIME_ENCODING_LINTS, &crl),
+            [
+                "rfc:crl_time_missing_seconds",
+                "rfc:crl_time_not_zulu",
+                "rfc:crl_time_fractional_seconds",
+            ]
+        );
+    }
+}
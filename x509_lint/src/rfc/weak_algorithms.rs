@@ -0,0 +1,408 @@
+use x509_parser::asn1_rs::Oid;
+use x509_parser::oid_registry::*;
+use x509_parser::prelude::{CertificateRevocationList, X509Certificate};
+use x509_parser::x509::AlgorithmIdentifier;
+
+use super::oids::*;
+use super::signature::rsa_pss_params;
+use crate::*;
+
+pub(crate) const WEAK_ALGORITHM_CONFIGURABLE_LINTS: &[(
+    LintDefinition,
+    ConfigurableCertificateLint,
+)] = &[
+    (SIGNATURE_MD_WEAK, signature_md_weak),
+    (SIGNATURE_SHA1, signature_sha1),
+    (SIGNATURE_SHA224, signature_sha224),
+    (SIGNATURE_DSA, signature_dsa),
+    (SPKI_DSA, spki_dsa),
+];
+
+pub(crate) const CRL_WEAK_ALGORITHM_CONFIGURABLE_LINTS: &[(LintDefinition, ConfigurableCRLLint)] =
+    &[
+        (CRL_SIGNATURE_MD_WEAK, crl_signature_md_weak),
+        (CRL_SIGNATURE_SHA1, crl_signature_sha1),
+        (CRL_SIGNATURE_SHA224, crl_signature_sha224),
+        (CRL_SIGNATURE_DSA, crl_signature_dsa),
+    ];
+
+/// Date after which CAs MUST NOT sign with SHA-1 (2016-01-01T00:00:00Z)
+const SHA1_DEPRECATION_TIMESTAMP: i64 = 1_451_606_400;
+
+/// Hash algorithms which are not allowed for signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WeakHash {
+    Md,
+    Sha1,
+    Sha224,
+}
+
+/// Signature algorithms using a weak hash algorithm
+const WEAK_HASH_SIGNATURES: &[(Oid<'static>, WeakHash)] = &[
+    (OID_PKCS1_MD2WITHRSAENC, WeakHash::Md),
+    (OID_PKCS1_MD4WITHRSAENC, WeakHash::Md),
+    (OID_PKCS1_MD5WITHRSAENC, WeakHash::Md),
+    (OID_PKCS1_SHA1WITHRSA, WeakHash::Sha1),
+    (OID_SIG_ECDSA_WITH_SHA1, WeakHash::Sha1),
+    (OID_SIG_DSA_WITH_SHA1, WeakHash::Sha1),
+    (OID_PKCS1_SHA224WITHRSA, WeakHash::Sha224),
+    (OID_SIG_ECDSA_WITH_SHA224, WeakHash::Sha224),
+    (OID_SIG_DSA_WITH_SHA224, WeakHash::Sha224),
+];
+
+/// Hash algorithms of RSASSA-PSS parameters which are weak
+const WEAK_PSS_HASHES: &[(Oid<'static>, WeakHash)] = &[
+    (OID_HASH_SHA1, WeakHash::Sha1),
+    (OID_NIST_HASH_SHA224, WeakHash::Sha224),
+];
+
+/// DSA signature algorithms
+const DSA_SIGNATURES: &[Oid<'static>] = &[
+    OID_SIG_DSA_WITH_SHA1,
+    OID_SIG_DSA_WITH_SHA224,
+    OID_SIG_DSA_WITH_SHA256,
+];
+
+fn is_allowed(oid: &Oid, config: &LintConfig) -> bool {
+    config.allowed_weak_algorithms.contains(oid)
+}
+
+/// Return the weak hash algorithm used by the signature algorithm, if any
+///
+/// Returns `None` if the algorithm (or the RSASSA-PSS hash) is allowed by the configuration.
+fn weak_hash(alg: &AlgorithmIdentifier, config: &LintConfig) -> Option<WeakHash> {
+    if is_allowed(&alg.algorithm, config) {
+        return None;
+    }
+    if let Some(&(_, hash)) = WEAK_HASH_SIGNATURES
+        .iter()
+        .find(|(oid, _)| *oid == alg.algorithm)
+    {
+        return Some(hash);
+    }
+    let Some(Ok(params)) = rsa_pss_params(alg) else {
+        return None;
+    };
+    let hash = params.hash_algorithm_oid();
+    if is_allowed(hash, config) {
+        return None;
+    }
+    WEAK_PSS_HASHES
+        .iter()
+        .find(|(oid, _)| oid == hash)
+        .map(|&(_, weak)| weak)
+}
+
+fn check_weak_hash(
+    alg: &AlgorithmIdentifier,
+    hash: WeakHash,
+    status: LintStatus,
+    config: &LintConfig,
+) -> LintResult {
+    if weak_hash(alg, config) == Some(hash) {
+        let details = LintDetails::new(alg.algorithm.to_id_string());
+        return LintResult::new_details(status, details);
+    }
+    LintResult::pass()
+}
+
+/// SHA-1 signatures are errors if issued after the deprecation date, and warnings otherwise
+fn sha1_status(timestamp: i64) -> LintStatus {
+    if timestamp >= SHA1_DEPRECATION_TIMESTAMP {
+        LintStatus::Error
+    } else {
+        LintStatus::Warn
+    }
+}
+
+fn check_dsa_signature(alg: &AlgorithmIdentifier, config: &LintConfig) -> LintResult {
+    if DSA_SIGNATURES.contains(&alg.algorithm) && !is_allowed(&alg.algorithm, config) {
+        let details = LintDetails::new(alg.algorithm.to_id_string());
+        return LintResult::new_details(LintStatus::Error, details);
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    SIGNATURE_MD_WEAK,
+    "rfc:signature_md_weak",
+    "MD2, MD4 and MD5 MUST NOT be used for signatures",
+    "CABF BR: 7.1.3.2"
+);
+pub(super) fn signature_md_weak(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    check_weak_hash(
+        &x509.signature_algorithm,
+        WeakHash::Md,
+        LintStatus::Error,
+        config,
+    )
+}
+
+lint_definition!(
+    SIGNATURE_SHA1,
+    "rfc:signature_sha1",
+    "SHA-1 MUST NOT be used for signatures after 2016-01-01",
+    "CABF BR: 7.1.3.2"
+);
+pub(super) fn signature_sha1(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    let status = sha1_status(x509.validity().not_before.timestamp());
+    check_weak_hash(&x509.signature_algorithm, WeakHash::Sha1, status, config)
+}
+
+lint_definition!(
+    SIGNATURE_SHA224,
+    "rfc:signature_sha224",
+    "SHA-224 is not an allowed hash algorithm for signatures",
+    "Mozilla Root Store Policy: 5.1"
+);
+pub(super) fn signature_sha224(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    check_weak_hash(
+        &x509.signature_algorithm,
+        WeakHash::Sha224,
+        LintStatus::Warn,
+        config,
+    )
+}
+
+lint_definition!(
+    SIGNATURE_DSA,
+    "rfc:signature_dsa",
+    "DSA is not an allowed signature algorithm",
+    "Mozilla Root Store Policy: 5.1"
+);
+pub(super) fn signature_dsa(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    check_dsa_signature(&x509.signature_algorithm, config)
+}
+
+lint_definition!(
+    SPKI_DSA,
+    "rfc:spki_dsa",
+    "DSA is not an allowed public key algorithm",
+    "Mozilla Root Store Policy: 5.1"
+);
+pub(super) fn spki_dsa(x509: &X509Certificate, config: &LintConfig) -> LintResult {
+    let alg = &x509.public_key().algorithm.algorithm;
+    if *alg == OID_KEY_TYPE_DSA && !is_allowed(alg, config) {
+        return LintResult::new(LintStatus::Error);
+    }
+    LintResult::pass()
+}
+
+lint_definition!(
+    CRL_SIGNATURE_MD_WEAK,
+    "rfc:crl_signature_md_weak",
+    "MD2, MD4 and MD5 MUST NOT be used for signatures",
+    "CABF BR: 7.1.3.2"
+);
+pub(super) fn crl_signature_md_weak(
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> LintResult {
+    check_weak_hash(
+        &crl.signature_algorithm,
+        WeakHash::Md,
+        LintStatus::Error,
+        config,
+    )
+}
+
+lint_definition!(
+    CRL_SIGNATURE_SHA1,
+    "rfc:crl_signature_sha1",
+    "SHA-1 MUST NOT be used for signatures after 2016-01-01",
+    "CABF BR: 7.1.3.2"
+);
+pub(super) fn crl_signature_sha1(
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> LintResult {
+    let status = sha1_status(crl.last_update().timestamp());
+    check_weak_hash(&crl.signature_algorithm, WeakHash::Sha1, status, config)
+}
+
+lint_definition!(
+    CRL_SIGNATURE_SHA224,
+    "rfc:crl_signature_sha224",
+    "SHA-224 is not an allowed hash algorithm for signatures",
+    "Mozilla Root Store Policy: 5.1"
+);
+pub(super) fn crl_signature_sha224(
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> LintResult {
+    check_weak_hash(
+        &crl.signature_algorithm,
+        WeakHash::Sha224,
+        LintStatus::Warn,
+        config,
+    )
+}
+
+lint_definition!(
+    CRL_SIGNATURE_DSA,
+    "rfc:crl_signature_dsa",
+    "DSA is not an allowed signature algorithm",
+    "Mozilla Root Store Policy: 5.1"
+);
+pub(super) fn crl_signature_dsa(
+    crl: &CertificateRevocationList,
+    config: &LintConfig,
+) -> LintResult {
+    check_dsa_signature(&crl.signature_algorithm, config)
+}
+
+#[cfg(test)]
+mod tests {
+    use x509_parser::prelude::FromDer;
+
+    use super::*;
+    use crate::rfc::spki::SPKI_CONFIGURABLE_LINTS;
+    use crate::rfc::testing::*;
+
+    fn rsa_signature(alg: &Oid) -> Vec<u8> {
+        seq(&[&oid(alg), &der(0x05, &[])])
+    }
+
+    fn cert_failures(cert: CertificateBuilder, config: &LintConfig) -> Vec<String> {
+        let data = cert.build();
+        let (_, x509) = X509Certificate::from_der(&data).unwrap();
+        cert_configurable_lint_failures(WEAK_ALGORITHM_CONFIGURABLE_LINTS, &x509, config)
+    }
+
+    fn signed_with(signature_algorithm: Vec<u8>) -> CertificateBuilder {
+        CertificateBuilder {
+            signature_algorithm,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn weak_signatures() {
+        let config = LintConfig::default();
+        assert!(cert_failures(CertificateBuilder::default(), &config).is_empty());
+
+        for alg in [OID_PKCS1_MD5WITHRSAENC, OID_PKCS1_MD2WITHRSAENC] {
+            let cert = signed_with(rsa_signature(&alg));
+            assert_eq!(cert_failures(cert, &config), ["rfc:signature_md_weak"]);
+        }
+        let cert = signed_with(seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA224)]));
+        assert_eq!(cert_failures(cert, &config), ["rfc:signature_sha224"]);
+        let cert = signed_with(seq(&[&oid(&OID_SIG_DSA_WITH_SHA256)]));
+        assert_eq!(cert_failures(cert, &config), ["rfc:signature_dsa"]);
+
+        let dsa_params = seq(&[&int(&[0x7f; 128]), &int(&[0x7f; 20]), &int(&[0x02])]);
+        let cert = CertificateBuilder {
+            spki: seq(&[
+                &seq(&[&oid(&OID_KEY_TYPE_DSA), &dsa_params]),
+                &bits(&int(&[0x3a; 128])),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(cert_failures(cert, &config), ["rfc:spki_dsa"]);
+    }
+
+    #[test]
+    fn sha1_deprecation() {
+        let config = LintConfig::default();
+        let sha1_cert = |validity: Vec<u8>| {
+            let data = CertificateBuilder {
+                signature_algorithm: rsa_signature(&OID_PKCS1_SHA1WITHRSA),
+                validity,
+                ..Default::default()
+            }
+            .build();
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            signature_sha1(&x509, &config).status
+        };
+        let before = seq(&[&der(0x17, b"150601000000Z"), &der(0x17, b"160601000000Z")]);
+        assert_eq!(sha1_cert(before), LintStatus::Warn);
+        let after = seq(&[&der(0x17, b"160101000000Z"), &der(0x17, b"170101000000Z")]);
+        assert_eq!(sha1_cert(after), LintStatus::Error);
+
+        let sha1_crl = |this_update: &[u8], next_update: &[u8]| {
+            let data = CrlBuilder {
+                signature_algorithm: rsa_signature(&OID_PKCS1_SHA1WITHRSA),
+                this_update: der(0x17, this_update),
+                next_update: Some(der(0x17, next_update)),
+                ..Default::default()
+            }
+            .build();
+            let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+            crl_signature_sha1(&crl, &config).status
+        };
+        assert_eq!(
+            sha1_crl(b"151231000000Z", b"160107000000Z"),
+            LintStatus::Warn
+        );
+        assert_eq!(
+            sha1_crl(b"250101000000Z", b"250108000000Z"),
+            LintStatus::Error
+        );
+    }
+
+    #[test]
+    fn crl_weak_signatures() {
+        let config = LintConfig::default();
+        for (signature_algorithm, lint) in [
+            (
+                rsa_signature(&OID_PKCS1_MD5WITHRSAENC),
+                "rfc:crl_signature_md_weak",
+            ),
+            (
+                seq(&[&oid(&OID_SIG_ECDSA_WITH_SHA224)]),
+                "rfc:crl_signature_sha224",
+            ),
+            (
+                seq(&[&oid(&OID_SIG_DSA_WITH_SHA256)]),
+                "rfc:crl_signature_dsa",
+            ),
+        ] {
+            let data = CrlBuilder {
+                signature_algorithm,
+                ..Default::default()
+            }
+            .build();
+            let (_, crl) = CertificateRevocationList::from_der(&data).unwrap();
+            let failures = crl_configurable_lint_failures(
+                CRL_WEAK_ALGORITHM_CONFIGURABLE_LINTS,
+                &crl,
+                &config,
+            );
+            assert_eq!(failures, [lint]);
+        }
+    }
+
+    #[test]
+    fn allowed_weak_algorithms() {
+        let cert = || signed_with(rsa_signature(&OID_PKCS1_SHA1WITHRSA));
+        let mut config = LintConfig::default();
+        assert_eq!(cert_failures(cert(), &config), ["rfc:signature_sha1"]);
+        config.allowed_weak_algorithms.push(OID_PKCS1_SHA1WITHRSA);
+        assert!(cert_failures(cert(), &config).is_empty());
+    }
+
+    /// 1024-bit RSA keys and P-192 keys are reported by the SPKI lints
+    #[test]
+    fn weak_keys() {
+        let config = LintConfig::default();
+        let spki_failures = |spki: Vec<u8>| {
+            let data = CertificateBuilder {
+                spki,
+                ..Default::default()
+            }
+            .build();
+            let (_, x509) = X509Certificate::from_der(&data).unwrap();
+            cert_configurable_lint_failures(SPKI_CONFIGURABLE_LINTS, &x509, &config)
+        };
+        let modulus_1024 = [&[0x00, 0xc1][..], &[0x5b; 126], &[0x01]].concat();
+        assert_eq!(
+            spki_failures(rsa_spki(&modulus_1024, &[0x01, 0x00, 0x01])),
+            ["rfc:spki_rsa_modulus_too_small"]
+        );
+        let point_p192 = [&[0x04][..], &[0x2a; 48]].concat();
+        assert_eq!(
+            spki_failures(ec_spki(&OID_EC_P192, &point_p192)),
+            ["rfc:spki_ec_curve_not_allowed"]
+        );
+    }
+}